@@ -0,0 +1,18 @@
+//! Throws arbitrary bytes at the webhook parser.
+//!
+//! Run with `cargo +nightly fuzz run webhook_parse` from the repository root.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use vortex_sdk::VortexWebhooks;
+
+fuzz_target!(|data: &[u8]| {
+    let webhooks = VortexWebhooks::new("whsec_fuzz")
+        .unwrap()
+        .with_max_json_depth(64);
+
+    // Any input must produce Ok or Err; a panic is a bug.
+    let _ = webhooks.try_parse(data);
+    let _ = webhooks.construct_events(data, "00");
+});
@@ -0,0 +1,196 @@
+//! Trait boundary over the Vortex API for dependency injection
+
+use async_trait::async_trait;
+
+use crate::client::VortexClient;
+use crate::error::VortexError;
+use crate::types::{
+    AcceptInvitationParam, AutojoinDomainsResponse, ConfigureAutojoinRequest,
+    CreateInvitationRequest, CreateInvitationResponse, DeleteResult, Invitation, InvitationGroup,
+    SyncInternalInvitationRequest, SyncInternalInvitationResponse,
+};
+
+/// The Vortex API operations, as an object-safe trait
+///
+/// Take `Arc<dyn VortexApi>` in your service layer to swap in a mock in tests.
+/// `VortexClient` implements every method by calling its inherent method of
+/// the same name; see those for details. Generic parameters are replaced by
+/// concrete types so the trait can be used as `dyn VortexApi`.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+/// use vortex_sdk::{VortexApi, VortexClient};
+///
+/// struct InviteService {
+///     vortex: Arc<dyn VortexApi>,
+/// }
+///
+/// let service = InviteService {
+///     vortex: Arc::new(VortexClient::new("VRTX.xxx.yyy".to_string())),
+/// };
+/// ```
+#[async_trait]
+pub trait VortexApi: Send + Sync {
+    async fn get_invitations_by_target(
+        &self,
+        target_type: &str,
+        target_value: &str,
+    ) -> Result<Vec<Invitation>, VortexError>;
+
+    async fn get_groups_by_target(
+        &self,
+        target_type: &str,
+        target_value: &str,
+    ) -> Result<Vec<InvitationGroup>, VortexError>;
+
+    async fn get_invitation(&self, invitation_id: &str) -> Result<Invitation, VortexError>;
+
+    async fn revoke_invitation(&self, invitation_id: &str) -> Result<(), VortexError>;
+
+    async fn deactivate_invitation(&self, invitation_id: &str) -> Result<Invitation, VortexError>;
+
+    async fn accept_invitations(
+        &self,
+        invitation_ids: Vec<String>,
+        param: AcceptInvitationParam,
+    ) -> Result<Invitation, VortexError>;
+
+    async fn accept_invitation(
+        &self,
+        invitation_id: &str,
+        param: AcceptInvitationParam,
+    ) -> Result<Invitation, VortexError>;
+
+    async fn get_invitations_by_group(
+        &self,
+        group_type: &str,
+        group_id: &str,
+    ) -> Result<Vec<Invitation>, VortexError>;
+
+    async fn delete_invitations_by_group(
+        &self,
+        group_type: &str,
+        group_id: &str,
+    ) -> Result<DeleteResult, VortexError>;
+
+    async fn reinvite(&self, invitation_id: &str) -> Result<Invitation, VortexError>;
+
+    async fn create_invitation(
+        &self,
+        request: &CreateInvitationRequest,
+    ) -> Result<CreateInvitationResponse, VortexError>;
+
+    async fn get_autojoin_domains(
+        &self,
+        scope_type: &str,
+        scope: &str,
+    ) -> Result<AutojoinDomainsResponse, VortexError>;
+
+    async fn configure_autojoin(
+        &self,
+        request: &ConfigureAutojoinRequest,
+    ) -> Result<AutojoinDomainsResponse, VortexError>;
+
+    async fn sync_internal_invitation(
+        &self,
+        request: &SyncInternalInvitationRequest,
+    ) -> Result<SyncInternalInvitationResponse, VortexError>;
+}
+
+#[async_trait]
+impl VortexApi for VortexClient {
+    async fn get_invitations_by_target(
+        &self,
+        target_type: &str,
+        target_value: &str,
+    ) -> Result<Vec<Invitation>, VortexError> {
+        VortexClient::get_invitations_by_target(self, target_type, target_value).await
+    }
+
+    async fn get_groups_by_target(
+        &self,
+        target_type: &str,
+        target_value: &str,
+    ) -> Result<Vec<InvitationGroup>, VortexError> {
+        VortexClient::get_groups_by_target(self, target_type, target_value).await
+    }
+
+    async fn get_invitation(&self, invitation_id: &str) -> Result<Invitation, VortexError> {
+        VortexClient::get_invitation(self, invitation_id).await
+    }
+
+    async fn revoke_invitation(&self, invitation_id: &str) -> Result<(), VortexError> {
+        VortexClient::revoke_invitation(self, invitation_id).await
+    }
+
+    async fn deactivate_invitation(&self, invitation_id: &str) -> Result<Invitation, VortexError> {
+        VortexClient::deactivate_invitation(self, invitation_id).await
+    }
+
+    async fn accept_invitations(
+        &self,
+        invitation_ids: Vec<String>,
+        param: AcceptInvitationParam,
+    ) -> Result<Invitation, VortexError> {
+        VortexClient::accept_invitations(self, invitation_ids, param).await
+    }
+
+    async fn accept_invitation(
+        &self,
+        invitation_id: &str,
+        param: AcceptInvitationParam,
+    ) -> Result<Invitation, VortexError> {
+        VortexClient::accept_invitation(self, invitation_id, param).await
+    }
+
+    async fn get_invitations_by_group(
+        &self,
+        group_type: &str,
+        group_id: &str,
+    ) -> Result<Vec<Invitation>, VortexError> {
+        VortexClient::get_invitations_by_group(self, group_type, group_id).await
+    }
+
+    async fn delete_invitations_by_group(
+        &self,
+        group_type: &str,
+        group_id: &str,
+    ) -> Result<DeleteResult, VortexError> {
+        VortexClient::delete_invitations_by_group(self, group_type, group_id).await
+    }
+
+    async fn reinvite(&self, invitation_id: &str) -> Result<Invitation, VortexError> {
+        VortexClient::reinvite(self, invitation_id).await
+    }
+
+    async fn create_invitation(
+        &self,
+        request: &CreateInvitationRequest,
+    ) -> Result<CreateInvitationResponse, VortexError> {
+        VortexClient::create_invitation(self, request).await
+    }
+
+    async fn get_autojoin_domains(
+        &self,
+        scope_type: &str,
+        scope: &str,
+    ) -> Result<AutojoinDomainsResponse, VortexError> {
+        VortexClient::get_autojoin_domains(self, scope_type, scope).await
+    }
+
+    async fn configure_autojoin(
+        &self,
+        request: &ConfigureAutojoinRequest,
+    ) -> Result<AutojoinDomainsResponse, VortexError> {
+        VortexClient::configure_autojoin(self, request).await
+    }
+
+    async fn sync_internal_invitation(
+        &self,
+        request: &SyncInternalInvitationRequest,
+    ) -> Result<SyncInternalInvitationResponse, VortexError> {
+        VortexClient::sync_internal_invitation(self, request).await
+    }
+}
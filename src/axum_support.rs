@@ -0,0 +1,106 @@
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+
+use crate::error::VortexError;
+
+/// Maps SDK errors to HTTP responses so axum handlers can `?`-propagate them.
+///
+/// - `WebhookSignatureError` → 401
+/// - `SerializationError` → 400
+/// - `RateLimited` → 429, with `Retry-After` when the API sent one
+/// - everything else → 500
+///
+/// Response bodies are generic JSON (`{"error": "..."}`) and never include the
+/// underlying error message, which may contain upstream API details.
+impl IntoResponse for VortexError {
+    fn into_response(self) -> Response {
+        if let VortexError::RateLimited { retry_after } = self {
+            let mut response = json_response(
+                StatusCode::TOO_MANY_REQUESTS,
+                serde_json::json!({ "error": "rate limited" }),
+            );
+            if let Some(retry_after) = retry_after {
+                response
+                    .headers_mut()
+                    .insert(header::RETRY_AFTER, retry_after.as_secs().into());
+            }
+            return response;
+        }
+
+        let (status, message) = match self {
+            VortexError::WebhookSignatureError(_) => {
+                (StatusCode::UNAUTHORIZED, "invalid webhook signature")
+            }
+            VortexError::SerializationError(_) => (StatusCode::BAD_REQUEST, "invalid payload"),
+            _ => (StatusCode::INTERNAL_SERVER_ERROR, "internal error"),
+        };
+        json_response(status, serde_json::json!({ "error": message }))
+    }
+}
+
+/// The canonical webhook acknowledgement: `200 OK` with `{"received": true}`.
+///
+/// # Example
+///
+/// ```no_run
+/// use axum::body::Bytes;
+/// use axum::http::HeaderMap;
+/// use axum::response::IntoResponse;
+/// use vortex_sdk::{webhook_ack, VortexError, VortexWebhooks};
+///
+/// async fn handle_webhook(headers: HeaderMap, body: Bytes) -> Result<impl IntoResponse, VortexError> {
+///     let webhooks = VortexWebhooks::new("whsec_your_secret")?;
+///     let event = webhooks.construct_event_from_parts(&headers, &body)?;
+///     println!("Received {}", event);
+///     Ok(webhook_ack())
+/// }
+/// ```
+pub fn webhook_ack() -> Response {
+    json_response(StatusCode::OK, serde_json::json!({ "received": true }))
+}
+
+fn json_response(status: StatusCode, body: serde_json::Value) -> Response {
+    (
+        status,
+        [(header::CONTENT_TYPE, "application/json")],
+        body.to_string(),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::SignatureErrorKind;
+
+    #[test]
+    fn test_error_status_codes() {
+        let cases = [
+            (
+                VortexError::signature(SignatureErrorKind::Mismatch, "bad"),
+                StatusCode::UNAUTHORIZED,
+            ),
+            (VortexError::SerializationError("bad".into()), StatusCode::BAD_REQUEST),
+            (VortexError::ApiError("secret detail".into()), StatusCode::INTERNAL_SERVER_ERROR),
+        ];
+        for (error, status) in cases {
+            assert_eq!(error.into_response().status(), status);
+        }
+
+        let limited = VortexError::RateLimited {
+            retry_after: Some(std::time::Duration::from_secs(30)),
+        };
+        let response = limited.into_response();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[header::RETRY_AFTER], "30");
+
+        let response = VortexError::RateLimited { retry_after: None }.into_response();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(response.headers().get(header::RETRY_AFTER).is_none());
+    }
+
+    #[test]
+    fn test_webhook_ack() {
+        assert_eq!(webhook_ack().status(), StatusCode::OK);
+    }
+}
@@ -1,65 +1,996 @@
-use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD};
+use base64::Engine;
+use futures_util::stream::{self, StreamExt};
 use hmac::{Hmac, Mac};
-use reqwest::Client as HttpClient;
+use reqwest::header::HeaderMap;
+use reqwest::{Client as HttpClient, Method};
+use serde::Serialize;
 use serde_json::json;
 use sha2::Sha256;
-use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use uuid::Uuid;
 
 use crate::error::VortexError;
+use crate::jwt::JwtBuilder;
+use crate::metrics::Metrics;
 use crate::types::*;
 
 type HmacSha256 = Hmac<Sha256>;
 
+type DryRunCallback = Arc<dyn Fn(&DryRunRequest) + Send + Sync>;
+
+type IdSource = Arc<dyn Fn() -> String + Send + Sync>;
+
+/// Secret material that is wiped on drop when the `zeroize` feature is enabled
+#[cfg(feature = "zeroize")]
+type Secret<T> = zeroize::Zeroizing<T>;
+#[cfg(not(feature = "zeroize"))]
+type Secret<T> = T;
+
+#[cfg(feature = "zeroize")]
+fn into_secret<T: zeroize::Zeroize>(value: T) -> Secret<T> {
+    zeroize::Zeroizing::new(value)
+}
+
+#[cfg(not(feature = "zeroize"))]
+fn into_secret<T>(value: T) -> Secret<T> {
+    value
+}
+
+/// Maximum length of the `role` claim in generated JWTs
+const MAX_ROLE_LENGTH: usize = 200;
+
+/// Default maximum size of generated JWTs, matching common cookie limits
+const DEFAULT_MAX_TOKEN_BYTES: usize = 4096;
+
+/// Maximum number of reinvite requests in flight in `reinvite_pending_by_group`
+const REINVITE_CONCURRENCY: usize = 5;
+
+/// Settings forwarded to `reqwest::ClientBuilder` when building the HTTP client
+#[derive(Debug, Clone, Default)]
+struct HttpClientConfig {
+    timeout: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Option<Duration>>,
+    tcp_keepalive: Option<Option<Duration>>,
+    http_version: HttpVersion,
+    proxies: Vec<reqwest::Proxy>,
+}
+
+impl HttpClientConfig {
+    fn build(&self) -> HttpClient {
+        let mut builder = HttpClient::builder();
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(keepalive) = self.tcp_keepalive {
+            builder = builder.tcp_keepalive(keepalive);
+        }
+        match self.http_version {
+            HttpVersion::Negotiate => {}
+            HttpVersion::Http1Only => builder = builder.http1_only(),
+            HttpVersion::Http2PriorKnowledge => builder = builder.http2_prior_knowledge(),
+        }
+        for proxy in &self.proxies {
+            builder = builder.proxy(proxy.clone());
+        }
+
+        builder.build().expect("Failed to build HTTP client")
+    }
+}
+
+/// In-memory cache of invitation list responses, keyed by request path and query
+struct InvitationCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, Vec<Invitation>)>>,
+    /// Bumped by `clear` (under the `entries` lock) so that lists fetched
+    /// before a mutation are not written back afterwards
+    generation: AtomicU64,
+}
+
+impl InvitationCache {
+    fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    /// The current generation, to capture before fetching and pass to `insert`
+    fn generation(&self) -> u64 {
+        let _entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        self.generation.load(Ordering::SeqCst)
+    }
+
+    fn get(&self, key: &str) -> Option<Vec<Invitation>> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries
+            .get(key)
+            .filter(|(stored_at, _)| stored_at.elapsed() < self.ttl)
+            .map(|(_, invitations)| invitations.clone())
+    }
+
+    /// Store a fetched list, unless the cache was cleared since `generation`
+    fn insert(&self, key: String, invitations: Vec<Invitation>, generation: u64) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if self.generation.load(Ordering::SeqCst) != generation {
+            return;
+        }
+        entries.retain(|_, (stored_at, _)| stored_at.elapsed() < self.ttl);
+        entries.insert(key, (Instant::now(), invitations));
+    }
+
+    fn clear(&self) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.clear();
+        self.generation.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// Per-id cache of `get_invitation` responses, revalidated with `If-None-Match`
+struct EtagCache {
+    config: CacheConfig,
+    entries: Mutex<HashMap<String, EtagEntry>>,
+    /// Bumped by `clear` (under the `entries` lock) so that responses to
+    /// requests sent before a mutation are not written back afterwards
+    generation: AtomicU64,
+}
+
+struct EtagEntry {
+    invitation: Invitation,
+    etag: Option<String>,
+    validated_at: Instant,
+    /// A background revalidation is in flight
+    revalidating: bool,
+}
+
+/// What `get_invitation` should do with a cached invitation
+#[derive(Debug)]
+enum CacheLookup {
+    /// Serve the cached invitation as is
+    Hit(Invitation),
+    /// Serve the stale invitation and revalidate it in the background with this ETag
+    Stale(Invitation, Option<String>),
+    /// Revalidate before serving, with the cached ETag if there is one
+    Miss(Option<String>),
+}
+
+impl EtagCache {
+    fn new(config: CacheConfig) -> Self {
+        Self {
+            config,
+            entries: Mutex::new(HashMap::new()),
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    /// Look up an invitation, returning the cache generation to pass to
+    /// `insert` and `touch`
+    ///
+    /// `allow_stale` is false when no background task can be spawned.
+    fn lookup(&self, id: &str, allow_stale: bool) -> (u64, CacheLookup) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let generation = self.generation.load(Ordering::SeqCst);
+        let Some(entry) = entries.get_mut(id) else {
+            return (generation, CacheLookup::Miss(None));
+        };
+
+        let age = entry.validated_at.elapsed();
+        if age < self.config.max_age {
+            return (generation, CacheLookup::Hit(entry.invitation.clone()));
+        }
+        let stale_until = self.config.max_age.saturating_add(self.config.stale_while_revalidate);
+        if allow_stale && age < stale_until {
+            if entry.revalidating {
+                return (generation, CacheLookup::Hit(entry.invitation.clone()));
+            }
+            entry.revalidating = true;
+            let stale = CacheLookup::Stale(entry.invitation.clone(), entry.etag.clone());
+            return (generation, stale);
+        }
+        (generation, CacheLookup::Miss(entry.etag.clone()))
+    }
+
+    /// Store a fetched invitation, unless the cache was cleared since `generation`
+    fn insert(&self, id: &str, invitation: Invitation, etag: Option<String>, generation: u64) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if self.generation.load(Ordering::SeqCst) != generation {
+            return;
+        }
+        if !entries.contains_key(id) && entries.len() >= self.config.max_entries {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.validated_at)
+                .map(|(id, _)| id.clone());
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+        if self.config.max_entries > 0 {
+            entries.insert(
+                id.to_string(),
+                EtagEntry {
+                    invitation,
+                    etag,
+                    validated_at: Instant::now(),
+                    revalidating: false,
+                },
+            );
+        }
+    }
+
+    /// Mark a cached invitation as still current after a `304`, returning it
+    ///
+    /// Returns `None` if the entry is gone or the cache was cleared since `generation`.
+    fn touch(&self, id: &str, etag: Option<String>, generation: u64) -> Option<Invitation> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if self.generation.load(Ordering::SeqCst) != generation {
+            return None;
+        }
+        let entry = entries.get_mut(id)?;
+        entry.validated_at = Instant::now();
+        entry.revalidating = false;
+        if etag.is_some() {
+            entry.etag = etag;
+        }
+        Some(entry.invitation.clone())
+    }
+
+    /// Apply the outcome of a background revalidation
+    async fn complete(&self, id: &str, response: Option<reqwest::Response>, generation: u64) {
+        let etag = response.as_ref().and_then(response_etag);
+        match response {
+            Some(response) if response.status() == reqwest::StatusCode::NOT_MODIFIED => {
+                self.touch(id, etag, generation);
+            }
+            Some(response) if response.status().is_success() => {
+                match response.json::<Invitation>().await {
+                    Ok(invitation) => self.insert(id, invitation, etag, generation),
+                    Err(_) => self.end_revalidation(id),
+                }
+            }
+            // Keep serving the stale copy; once it ages out, the next call
+            // revalidates inline and surfaces the error
+            _ => self.end_revalidation(id),
+        }
+    }
+
+    fn end_revalidation(&self, id: &str) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(entry) = entries.get_mut(id) {
+            entry.revalidating = false;
+        }
+    }
+
+    fn clear(&self) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.clear();
+        self.generation.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// Read the `ETag` header of a response
+fn response_etag(response: &reqwest::Response) -> Option<String> {
+    response
+        .headers()
+        .get(reqwest::header::ETAG)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string())
+}
+
+/// The `X-Request-Id` response header formatted for an error message, or empty
+fn request_id_note(response: &reqwest::Response) -> String {
+    header_request_id_note(response.headers())
+}
+
+/// The `X-Request-Id` header of a request or response formatted for an error message, or empty
+fn header_request_id_note(headers: &HeaderMap) -> String {
+    headers
+        .get("X-Request-Id")
+        .and_then(|v| v.to_str().ok())
+        .map(|id| format!(" [request id: {}]", id))
+        .unwrap_or_default()
+}
+
+/// Cloneable handle on the configured transport, for requests sent from spawned tasks
+#[derive(Clone)]
+struct Transport {
+    http_client: HttpClient,
+    #[cfg(feature = "middleware")]
+    middleware_client: Option<reqwest_middleware::ClientWithMiddleware>,
+}
+
+impl Transport {
+    /// Execute a built request
+    ///
+    /// Errors are returned as messages without the request URL, which the
+    /// caller appends in redacted form.
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response, String> {
+        #[cfg(feature = "middleware")]
+        if let Some(client) = &self.middleware_client {
+            return client.execute(request).await.map_err(|e| match e {
+                reqwest_middleware::Error::Reqwest(e) => e.without_url().to_string(),
+                e => e.to_string(),
+            });
+        }
+
+        self.http_client
+            .execute(request)
+            .await
+            .map_err(|e| e.without_url().to_string())
+    }
+}
+
+/// Circuit breaker state shared by all requests made through a client
+struct CircuitBreaker {
+    config: CircuitConfig,
+    state: Mutex<CircuitState>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum CircuitState {
+    Closed { failures: u32, first_failure: Instant },
+    Open { until: Instant },
+    /// A probe request was let through at `since`
+    HalfOpen { since: Instant },
+}
+
+impl CircuitBreaker {
+    fn new(config: CircuitConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(CircuitState::Closed {
+                failures: 0,
+                first_failure: Instant::now(),
+            }),
+        }
+    }
+
+    /// Check whether a request may be sent, moving an expired open circuit to half-open
+    fn acquire(&self) -> Result<(), VortexError> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        match *state {
+            CircuitState::Closed { .. } => Ok(()),
+            CircuitState::Open { until } if now < until => Err(VortexError::CircuitOpen(
+                format!("retrying in {:?}", until - now),
+            )),
+            // A probe that never reported back (e.g. was cancelled) is replaced after a cooldown
+            CircuitState::HalfOpen { since } if now.duration_since(since) < self.config.cooldown => {
+                Err(VortexError::CircuitOpen("probe request in flight".to_string()))
+            }
+            _ => {
+                *state = CircuitState::HalfOpen { since: now };
+                Ok(())
+            }
+        }
+    }
+
+    fn record(&self, success: bool) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        *state = match (*state, success) {
+            (_, true) => CircuitState::Closed {
+                failures: 0,
+                first_failure: now,
+            },
+            (CircuitState::Closed { failures, first_failure }, false) => {
+                let (failures, first_failure) =
+                    if failures == 0 || now.duration_since(first_failure) > self.config.window {
+                        (1, now)
+                    } else {
+                        (failures + 1, first_failure)
+                    };
+                if failures >= self.config.failure_threshold {
+                    CircuitState::Open {
+                        until: now + self.config.cooldown,
+                    }
+                } else {
+                    CircuitState::Closed {
+                        failures,
+                        first_failure,
+                    }
+                }
+            }
+            (CircuitState::HalfOpen { .. }, false) => CircuitState::Open {
+                until: now + self.config.cooldown,
+            },
+            (open @ CircuitState::Open { .. }, false) => open,
+        };
+    }
+}
+
 /// Vortex Rust SDK Client
 ///
 /// Provides JWT generation and Vortex API integration for Rust applications.
 /// Compatible with React providers and follows the same paradigms as other Vortex SDKs.
 pub struct VortexClient {
-    api_key: String,
+    api_key: Secret<String>,
     base_url: String,
     http_client: HttpClient,
+    http_config: HttpClientConfig,
+    #[cfg(feature = "middleware")]
+    middleware_client: Option<reqwest_middleware::ClientWithMiddleware>,
+    jwt_options: JwtOptions,
+    cache: Option<InvitationCache>,
+    invitation_cache: Option<Arc<EtagCache>>,
+    circuit_breaker: Option<CircuitBreaker>,
+    metrics: Option<Arc<dyn Metrics>>,
+    auth_scheme: AuthScheme,
+    id_source: Option<IdSource>,
+    allowed_roles: Option<Vec<String>>,
+    default_role: Option<String>,
+    default_identifiers: Option<Vec<Identifier>>,
+    max_token_bytes: Option<usize>,
+    emit_request_ids: bool,
+    dry_run: bool,
+    dry_run_callback: Option<DryRunCallback>,
 }
 
-impl VortexClient {
-    /// Create a new Vortex client
+impl VortexClient {
+    /// Create a new Vortex client
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - Your Vortex API key
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vortex_sdk::VortexClient;
+    ///
+    /// let api_key = "VRTX.your_encoded_id.your_key".to_string();
+    /// let client = VortexClient::new(api_key);
+    /// ```
+    pub fn new(api_key: String) -> Self {
+        let base_url = std::env::var("VORTEX_API_BASE_URL")
+            .unwrap_or_else(|_| "https://api.vortexsoftware.com".to_string());
+
+        Self::with_base_url(api_key, base_url)
+    }
+
+    /// Create a new Vortex client from the environment
+    ///
+    /// Reads the API key from `VORTEX_API_KEY` and validates its format. The
+    /// base URL is read from `VORTEX_API_BASE_URL` if set, as with `new`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VortexError::InvalidApiKey` if `VORTEX_API_KEY` is missing or malformed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use vortex_sdk::VortexClient;
+    ///
+    /// let client = VortexClient::from_env().expect("Vortex is not configured");
+    /// ```
+    pub fn from_env() -> Result<Self, VortexError> {
+        let api_key = std::env::var("VORTEX_API_KEY").map_err(|e| {
+            VortexError::InvalidApiKey(format!("Failed to read VORTEX_API_KEY: {}", e))
+        })?;
+        Self::try_from(api_key.as_str())
+    }
+
+    /// Parse and validate an API key without keeping its secret
+    ///
+    /// Applies the same format checks as `generate_jwt`, except that an empty
+    /// secret is reported through `secret_present` rather than rejected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vortex_sdk::VortexClient;
+    ///
+    /// let parts = VortexClient::parse_api_key("VRTX.AAAAAAAAAAAAAAAAAAAAAA.test_secret_key").unwrap();
+    /// assert_eq!(parts.prefix, "VRTX");
+    /// assert_eq!(parts.account_uuid.to_string(), "00000000-0000-0000-0000-000000000000");
+    /// assert!(parts.secret_present);
+    /// ```
+    pub fn parse_api_key(key: &str) -> Result<ApiKeyParts, VortexError> {
+        let (prefix, account_uuid, secret) = parse_key_segments(key)?;
+        Ok(ApiKeyParts {
+            prefix: prefix.to_string(),
+            account_uuid,
+            secret_present: !secret.is_empty(),
+        })
+    }
+
+    /// Create a new Vortex client with a custom base URL
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - Your Vortex API key
+    /// * `base_url` - Custom base URL for the Vortex API
+    pub fn with_base_url(api_key: String, base_url: String) -> Self {
+        Self {
+            api_key: into_secret(api_key),
+            base_url,
+            http_client: HttpClient::new(),
+            http_config: HttpClientConfig::default(),
+            #[cfg(feature = "middleware")]
+            middleware_client: None,
+            jwt_options: JwtOptions::default(),
+            cache: None,
+            invitation_cache: None,
+            circuit_breaker: None,
+            metrics: None,
+            auth_scheme: AuthScheme::default(),
+            id_source: None,
+            allowed_roles: None,
+            default_role: None,
+            default_identifiers: None,
+            max_token_bytes: Some(DEFAULT_MAX_TOKEN_BYTES),
+            emit_request_ids: false,
+            dry_run: false,
+            dry_run_callback: None,
+        }
+    }
+
+    /// Create a new Vortex client from serializable settings
+    ///
+    /// Equivalent to calling `new` (or `with_base_url` when `config.base_url`
+    /// is set) followed by the builder methods for each configured field.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be built, under the same conditions
+    /// as `reqwest::Client::new`.
+    pub fn from_config(api_key: String, config: VortexClientConfig) -> Self {
+        let mut client = match config.base_url {
+            Some(base_url) => Self::with_base_url(api_key, base_url),
+            None => Self::new(api_key),
+        };
+
+        client.http_config.timeout = config.timeout;
+        client.http_config.pool_max_idle_per_host = config.pool_max_idle_per_host;
+        client.http_config.pool_idle_timeout = config.pool_idle_timeout.map(Some);
+        client.http_config.tcp_keepalive = config.tcp_keepalive.map(Some);
+        client.http_config.http_version = config.http_version;
+        client.http_client = client.http_config.build();
+
+        client.auth_scheme = config.auth_scheme;
+        client.cache = config.cache_ttl.map(InvitationCache::new);
+        client.invitation_cache = config.invitation_cache.map(|c| Arc::new(EtagCache::new(c)));
+        client.circuit_breaker = config.circuit_breaker.map(CircuitBreaker::new);
+        client.allowed_roles = config.allowed_roles;
+        client.default_role = config.default_role;
+        client.default_identifiers = config.default_identifiers;
+        client.dry_run = config.dry_run;
+        client
+    }
+
+    /// Set the default timeout for API requests
+    ///
+    /// Applies from connecting until the response body has been read. Override
+    /// it per call with `RequestOptions::with_timeout` or
+    /// `ListInvitationsOptions::with_timeout`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be rebuilt, under the same conditions
+    /// as `reqwest::Client::new`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.timeout = Some(timeout);
+        self.http_client = self.http_config.build();
+        self
+    }
+
+    /// Set the maximum number of idle connections kept per host
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be rebuilt, under the same conditions
+    /// as `reqwest::Client::new`.
+    pub fn with_pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.http_config.pool_max_idle_per_host = Some(max);
+        self.http_client = self.http_config.build();
+        self
+    }
+
+    /// Set how long idle pooled connections are kept alive (`None` disables the timeout)
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be rebuilt, under the same conditions
+    /// as `reqwest::Client::new`.
+    pub fn with_pool_idle_timeout(mut self, timeout: impl Into<Option<Duration>>) -> Self {
+        self.http_config.pool_idle_timeout = Some(timeout.into());
+        self.http_client = self.http_config.build();
+        self
+    }
+
+    /// Set the TCP keep-alive interval for connections (`None` disables keep-alive)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use vortex_sdk::VortexClient;
+    ///
+    /// let client = VortexClient::new("VRTX.xxx.yyy".to_string())
+    ///     .with_pool_max_idle_per_host(32)
+    ///     .with_pool_idle_timeout(Duration::from_secs(90))
+    ///     .with_tcp_keepalive(Duration::from_secs(60));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be rebuilt, under the same conditions
+    /// as `reqwest::Client::new`.
+    pub fn with_tcp_keepalive(mut self, keepalive: impl Into<Option<Duration>>) -> Self {
+        self.http_config.tcp_keepalive = Some(keepalive.into());
+        self.http_client = self.http_config.build();
+        self
+    }
+
+    /// Choose the HTTP version used for API requests
+    ///
+    /// `HttpVersion::Http2PriorKnowledge` multiplexes concurrent requests over
+    /// fewer connections, but fails against servers or proxies that do not
+    /// speak HTTP/2. To negotiate HTTP/2 over TLS instead, enable the
+    /// `http2-alpn` Cargo feature and keep the default `HttpVersion::Negotiate`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vortex_sdk::{HttpVersion, VortexClient};
+    ///
+    /// let client = VortexClient::new("VRTX.xxx.yyy".to_string())
+    ///     .with_http_version(HttpVersion::Http2PriorKnowledge);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be rebuilt, under the same conditions
+    /// as `reqwest::Client::new`.
+    pub fn with_http_version(mut self, version: HttpVersion) -> Self {
+        self.http_config.http_version = version;
+        self.http_client = self.http_config.build();
+        self
+    }
+
+    /// Use HTTP/2 without negotiation (see [`VortexClient::with_http_version`])
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be rebuilt, under the same conditions
+    /// as `reqwest::Client::new`.
+    pub fn with_http2_prior_knowledge(self) -> Self {
+        self.with_http_version(HttpVersion::Http2PriorKnowledge)
+    }
+
+    /// Send requests through a `reqwest-middleware` stack
+    ///
+    /// Lets tracing, retry and other middleware shared across your services
+    /// wrap Vortex API calls. Requests are still built by the SDK (headers,
+    /// query, body); only their execution goes through `client`. Connection
+    /// settings such as `with_pool_max_idle_per_host` and `with_proxy` do not
+    /// apply to it and must be configured on the client you pass in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use reqwest_middleware::ClientBuilder;
+    /// use vortex_sdk::VortexClient;
+    ///
+    /// let middleware_client = ClientBuilder::new(reqwest::Client::new()).build();
+    /// let client = VortexClient::new("VRTX.xxx.yyy".to_string())
+    ///     .with_middleware_client(middleware_client);
+    /// ```
+    #[cfg(feature = "middleware")]
+    pub fn with_middleware_client(
+        mut self,
+        client: reqwest_middleware::ClientWithMiddleware,
+    ) -> Self {
+        self.middleware_client = Some(client);
+        self
+    }
+
+    /// Set how the API key is sent with requests
+    ///
+    /// Defaults to `AuthScheme::ApiKeyHeader` (`x-api-key`). Use
+    /// `AuthScheme::Bearer` to send `Authorization: Bearer <key>` instead, e.g.
+    /// when routing through a gateway that only forwards standard auth headers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vortex_sdk::{AuthScheme, VortexClient};
+    ///
+    /// let client = VortexClient::new("VRTX.xxx.yyy".to_string())
+    ///     .with_auth_scheme(AuthScheme::Bearer);
+    /// ```
+    pub fn with_auth_scheme(mut self, auth_scheme: AuthScheme) -> Self {
+        self.auth_scheme = auth_scheme;
+        self
+    }
+
+    /// Restrict the `role` claim accepted by `generate_jwt` to an allow-list
+    ///
+    /// When set, generating a JWT whose `role` is not in the list returns
+    /// `VortexError::InvalidRequest`, catching typos at mint time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use vortex_sdk::{VortexClient, User};
+    ///
+    /// let client = VortexClient::new("VRTX.AAAAAAAAAAAAAAAAAAAAAA.test_secret_key".to_string())
+    ///     .with_allowed_roles(vec!["admin".to_string(), "member".to_string()]);
+    /// let user = User::new("user-123", "user@example.com");
+    ///
+    /// let mut extra = HashMap::new();
+    /// extra.insert("role".to_string(), serde_json::json!("admim"));
+    /// assert!(client.generate_jwt(&user, Some(extra)).is_err());
+    /// ```
+    pub fn with_allowed_roles(mut self, roles: Vec<String>) -> Self {
+        self.allowed_roles = Some(roles);
+        self
+    }
+
+    /// Set the `role` claim used by `generate_jwt` when `extra` has none
+    pub fn with_default_role(mut self, role: impl Into<String>) -> Self {
+        self.default_role = Some(role.into());
+        self
+    }
+
+    /// Set the `identifiers` claim used by `generate_jwt` when `extra` has none or an empty list
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vortex_sdk::{Identifier, VortexClient, User};
+    ///
+    /// let client = VortexClient::new("VRTX.AAAAAAAAAAAAAAAAAAAAAA.test_secret_key".to_string())
+    ///     .with_default_role("member")
+    ///     .with_default_identifiers(vec![Identifier::email("team@example.com")]);
+    /// let user = User::new("user-123", "user@example.com");
+    ///
+    /// // Uses role "member" and the default identifiers
+    /// let jwt = client.generate_jwt(&user, None).unwrap();
+    /// ```
+    pub fn with_default_identifiers(mut self, identifiers: Vec<Identifier>) -> Self {
+        self.default_identifiers = Some(identifiers);
+        self
+    }
+
+    /// Set the maximum size of JWTs minted by `generate_jwt` (`None` disables the check)
+    ///
+    /// Defaults to 4096 bytes. Larger tokens are rejected with
+    /// `VortexError::InvalidRequest` instead of being silently truncated by a
+    /// cookie store or header limit downstream.
+    pub fn with_max_token_bytes(mut self, max: impl Into<Option<usize>>) -> Self {
+        self.max_token_bytes = max.into();
+        self
+    }
+
+    /// Check that a `role` claim is a bounded string and, if configured, in the allow-list
+    fn validate_role(&self, role: &serde_json::Value) -> Result<(), VortexError> {
+        let role = role
+            .as_str()
+            .ok_or_else(|| VortexError::InvalidRequest("role must be a string".to_string()))?;
+
+        if role.is_empty() || role.len() > MAX_ROLE_LENGTH {
+            return Err(VortexError::InvalidRequest(format!(
+                "role must be between 1 and {} characters",
+                MAX_ROLE_LENGTH
+            )));
+        }
+
+        if let Some(ref allowed) = self.allowed_roles {
+            if !allowed.iter().any(|r| r == role) {
+                return Err(VortexError::InvalidRequest(format!(
+                    "Unknown role: {}",
+                    role
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Set the generator used for SDK-generated ids (idempotency keys, request ids)
+    ///
+    /// Defaults to a random v4 UUID. Inject a deterministic source in tests, or
+    /// derive ids from your own trace ids.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicU64, Ordering};
+    /// use vortex_sdk::VortexClient;
+    ///
+    /// let counter = AtomicU64::new(0);
+    /// let client = VortexClient::new("VRTX.xxx.yyy".to_string())
+    ///     .with_id_source(move || format!("req-{}", counter.fetch_add(1, Ordering::SeqCst)));
+    ///
+    /// assert_eq!(client.next_id(), "req-0");
+    /// assert_eq!(client.next_id(), "req-1");
+    /// ```
+    pub fn with_id_source<F>(mut self, id_source: F) -> Self
+    where
+        F: Fn() -> String + Send + Sync + 'static,
+    {
+        self.id_source = Some(Arc::new(id_source));
+        self
+    }
+
+    /// Send a fresh `X-Request-Id` header with every request
+    ///
+    /// Ids come from the id source (see `with_id_source`), so they can be
+    /// derived from your own trace ids. `ApiError` messages quote the request
+    /// id the API echoes back in its `X-Request-Id` response header; give
+    /// that id to Vortex support to locate a failed call.
+    pub fn with_request_ids(mut self, enabled: bool) -> Self {
+        self.emit_request_ids = enabled;
+        self
+    }
+
+    /// Generate a new id from the configured id source
+    pub fn next_id(&self) -> String {
+        match &self.id_source {
+            Some(id_source) => id_source(),
+            None => Uuid::new_v4().to_string(),
+        }
+    }
+
+    /// Cache invitation list responses in memory for `ttl`
+    ///
+    /// Applies to `get_invitations_by_target` and `get_invitations_by_group`.
+    /// Entries are keyed by request path and query parameters. Any mutating
+    /// request (revoke, accept, reinvite, ...) clears the whole cache, since
+    /// the affected groups and targets can't always be known up front.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use vortex_sdk::VortexClient;
+    ///
+    /// let client = VortexClient::new("VRTX.xxx.yyy".to_string())
+    ///     .with_cache(Duration::from_secs(10));
+    /// ```
+    pub fn with_cache(mut self, ttl: Duration) -> Self {
+        self.cache = Some(InvitationCache::new(ttl));
+        self
+    }
+
+    /// Cache `get_invitation` responses per invitation id, keyed by ETag
+    ///
+    /// Repeated lookups of the same id send the cached ETag as
+    /// `If-None-Match` and return the cached invitation on `304 Not Modified`.
+    /// See [`CacheConfig`] for serving cached or stale copies without waiting
+    /// on the API. Background revalidations need a tokio runtime and bypass
+    /// the circuit breaker and metrics. Like `with_cache`, any mutating
+    /// request clears the whole cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use vortex_sdk::{CacheConfig, VortexClient};
+    ///
+    /// let client = VortexClient::new("VRTX.xxx.yyy".to_string()).with_invitation_cache(
+    ///     CacheConfig::new()
+    ///         .with_max_age(Duration::from_secs(5))
+    ///         .with_stale_while_revalidate(Duration::from_secs(30)),
+    /// );
+    /// ```
+    pub fn with_invitation_cache(mut self, config: CacheConfig) -> Self {
+        self.invitation_cache = Some(Arc::new(EtagCache::new(config)));
+        self
+    }
+
+    /// Fail fast while the Vortex API is down instead of piling up requests
+    ///
+    /// See [`CircuitConfig`] for how the circuit opens and recovers. While it
+    /// is open, API methods return `VortexError::CircuitOpen` without sending
+    /// a request. JWT generation is unaffected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use vortex_sdk::{CircuitConfig, VortexClient};
+    ///
+    /// let client = VortexClient::new("VRTX.xxx.yyy".to_string()).with_circuit_breaker(
+    ///     CircuitConfig::new()
+    ///         .with_failure_threshold(3)
+    ///         .with_cooldown(Duration::from_secs(10)),
+    /// );
+    /// ```
+    pub fn with_circuit_breaker(mut self, config: CircuitConfig) -> Self {
+        self.circuit_breaker = Some(CircuitBreaker::new(config));
+        self
+    }
+
+    /// Set the default JWT options used by `generate_jwt`
+    pub fn with_jwt_options(mut self, options: JwtOptions) -> Self {
+        self.jwt_options = options;
+        self
+    }
+
+    /// Route requests through the given proxy
+    ///
+    /// By default the client already honors the standard `HTTP_PROXY`,
+    /// `HTTPS_PROXY` and `NO_PROXY` environment variables. Adding a proxy here
+    /// takes precedence over them. Can be called multiple times.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vortex_sdk::VortexClient;
+    ///
+    /// let client = VortexClient::new("VRTX.xxx.yyy".to_string())
+    ///     .with_proxy(reqwest::Proxy::https("http://proxy.internal:3128").unwrap());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be rebuilt, under the same conditions
+    /// as `reqwest::Client::new`.
+    pub fn with_proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.http_config.proxies.push(proxy);
+        self.http_client = self.http_config.build();
+        self
+    }
+
+    /// Register an observer for request counts, errors and latency
     ///
-    /// # Arguments
+    /// The observer is notified around every HTTP request the client sends;
+    /// requests skipped in dry-run mode or rejected by an open circuit breaker
+    /// are not reported. See [`Metrics`] for an example.
+    pub fn with_metrics(mut self, metrics: Arc<dyn Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Enable or disable dry-run mode
     ///
-    /// * `api_key` - Your Vortex API key
+    /// When enabled, every request other than GET and HEAD (so POST, PUT,
+    /// PATCH and DELETE) is not sent. The request is passed to the dry-run
+    /// callback (or logged to stderr if none is set) and a synthesized success
+    /// is returned. GET and HEAD requests still execute.
+    ///
+    /// If the method's response type cannot be synthesized from an empty body,
+    /// `VortexError::DryRun` is returned instead.
     ///
     /// # Example
     ///
     /// ```
     /// use vortex_sdk::VortexClient;
     ///
-    /// let api_key = "VRTX.your_encoded_id.your_key".to_string();
-    /// let client = VortexClient::new(api_key);
+    /// let client = VortexClient::new("VRTX.xxx.yyy".to_string())
+    ///     .with_dry_run(true)
+    ///     .with_dry_run_callback(|req| {
+    ///         println!("Would send {} {}", req.method, req.path);
+    ///     });
     /// ```
-    pub fn new(api_key: String) -> Self {
-        let base_url = std::env::var("VORTEX_API_BASE_URL")
-            .unwrap_or_else(|_| "https://api.vortexsoftware.com".to_string());
-
-        Self {
-            api_key,
-            base_url,
-            http_client: HttpClient::new(),
-        }
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
     }
 
-    /// Create a new Vortex client with a custom base URL
-    ///
-    /// # Arguments
-    ///
-    /// * `api_key` - Your Vortex API key
-    /// * `base_url` - Custom base URL for the Vortex API
-    pub fn with_base_url(api_key: String, base_url: String) -> Self {
-        Self {
-            api_key,
-            base_url,
-            http_client: HttpClient::new(),
-        }
+    /// Set the callback invoked for each request skipped in dry-run mode
+    pub fn with_dry_run_callback<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&DryRunRequest) + Send + Sync + 'static,
+    {
+        self.dry_run_callback = Some(Arc::new(callback));
+        self
     }
 
     /// Generate a JWT token for a user
@@ -97,63 +1028,157 @@ impl VortexClient {
         user: &User,
         extra: Option<HashMap<String, serde_json::Value>>,
     ) -> Result<String, VortexError> {
-        // Parse API key: format is VRTX.base64encodedId.key
-        let parts: Vec<&str> = self.api_key.split('.').collect();
-        if parts.len() != 3 {
-            return Err(VortexError::InvalidApiKey(
-                "Invalid API key format".to_string(),
-            ));
-        }
+        self.generate_jwt_with_options(user, extra, &self.jwt_options)
+    }
 
-        let prefix = parts[0];
-        let encoded_id = parts[1];
-        let key = parts[2];
+    /// Start building a JWT for a user with chainable options
+    ///
+    /// The builder starts from the client's `JwtOptions`. See [`JwtBuilder`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vortex_sdk::{User, VortexClient};
+    ///
+    /// let client = VortexClient::new("VRTX.AAAAAAAAAAAAAAAAAAAAAA.test_secret_key".to_string());
+    /// let user = User::new("user-123", "user@example.com");
+    /// let jwt = client.jwt(&user).typ("at+jwt").issued_at(1767225600).sign().unwrap();
+    /// ```
+    pub fn jwt<'a>(&'a self, user: &'a User) -> JwtBuilder<'a> {
+        JwtBuilder::new(self, user, self.jwt_options.clone())
+    }
 
-        if prefix != "VRTX" {
-            return Err(VortexError::InvalidApiKey(
-                "Invalid API key prefix".to_string(),
-            ));
-        }
+    /// Generate a JWT token with identifiers and groups of any serializable type
+    ///
+    /// Lets you pass your own identifier/group types directly, as long as they
+    /// serialize to the expected shape: identifiers as `{"type", "value"}` objects
+    /// and groups as `{"type", "name", ...}` objects. The SDK's `Identifier` and
+    /// `Group` types work too. They are added to the payload as `identifiers`
+    /// and `groups`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde::Serialize;
+    /// use vortex_sdk::{Group, VortexClient, User};
+    ///
+    /// #[derive(Serialize)]
+    /// struct MyIdentifier {
+    ///     #[serde(rename = "type")]
+    ///     kind: &'static str,
+    ///     value: String,
+    ///     verified: bool,
+    /// }
+    ///
+    /// let client = VortexClient::new("VRTX.AAAAAAAAAAAAAAAAAAAAAA.test_secret_key".to_string());
+    /// let user = User::new("user-123", "user@example.com");
+    /// let identifiers = vec![MyIdentifier {
+    ///     kind: "email",
+    ///     value: "user@example.com".to_string(),
+    ///     verified: true,
+    /// }];
+    /// let groups = vec![Group::new("workspace", "Engineering").with_group_id("ws-1")];
+    ///
+    /// let jwt = client.generate_jwt_with(&user, &identifiers, &groups, None).unwrap();
+    /// ```
+    pub fn generate_jwt_with<I: Serialize, G: Serialize>(
+        &self,
+        user: &User,
+        identifiers: &[I],
+        groups: &[G],
+        extra: Option<HashMap<String, serde_json::Value>>,
+    ) -> Result<String, VortexError> {
+        let identifiers = serde_json::to_value(identifiers)
+            .map_err(|e| VortexError::SerializationError(e.to_string()))?;
+        let groups = serde_json::to_value(groups)
+            .map_err(|e| VortexError::SerializationError(e.to_string()))?;
 
-        // Decode the UUID from base64url
-        let id_bytes = URL_SAFE_NO_PAD
-            .decode(encoded_id)
-            .map_err(|e| VortexError::InvalidApiKey(format!("Failed to decode ID: {}", e)))?;
+        check_shape(&identifiers, "identifier", &["type", "value"])?;
+        check_shape(&groups, "group", &["type", "name"])?;
 
-        if id_bytes.len() != 16 {
-            return Err(VortexError::InvalidApiKey("ID must be 16 bytes".to_string()));
-        }
+        let mut extra = extra.unwrap_or_default();
+        extra.insert("identifiers".to_string(), identifiers);
+        extra.insert("groups".to_string(), groups);
 
-        let uuid = Uuid::from_slice(&id_bytes)
-            .map_err(|e| VortexError::InvalidApiKey(format!("Invalid UUID: {}", e)))?;
-        let uuid_str = uuid.to_string();
+        self.generate_jwt(user, Some(extra))
+    }
 
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-        let expires = now + 3600; // 1 hour from now
+    /// Generate a JWT token for a user with explicit JWT options
+    ///
+    /// Same as `generate_jwt`, but uses `options` instead of the client's
+    /// default JWT options.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vortex_sdk::{JwtOptions, VortexClient, User};
+    ///
+    /// let client = VortexClient::new("VRTX.AAAAAAAAAAAAAAAAAAAAAA.test_secret_key".to_string());
+    /// let user = User::new("user-123", "user@example.com");
+    ///
+    /// // Move `iat` from the header to the payload for stricter verifiers
+    /// let options = JwtOptions::new()
+    ///     .include_iat_in_header(false)
+    ///     .include_iat_in_payload(true);
+    /// let jwt = client.generate_jwt_with_options(&user, None, &options).unwrap();
+    /// ```
+    pub fn generate_jwt_with_options(
+        &self,
+        user: &User,
+        extra: Option<HashMap<String, serde_json::Value>>,
+        options: &JwtOptions,
+    ) -> Result<String, VortexError> {
+        let (uuid_str, key) = split_api_key(&self.api_key)?;
+
+        let now = match options.issued_at {
+            Some(issued_at) => issued_at,
+            None => SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_err(|e| VortexError::CryptoError(format!("System clock error: {}", e)))?
+                .as_secs(),
+        };
+        let expires = now
+            .checked_add(3600) // 1 hour from now
+            .ok_or_else(|| VortexError::InvalidRequest("expiry overflow".to_string()))?;
+
+        let kid = options.kid.as_deref().unwrap_or(&uuid_str);
 
         // Step 1: Derive signing key from API key + ID
-        let mut hmac = HmacSha256::new_from_slice(key.as_bytes())
-            .map_err(|e| VortexError::CryptoError(format!("HMAC error: {}", e)))?;
-        hmac.update(uuid_str.as_bytes());
-        let signing_key = hmac.finalize().into_bytes();
+        let signing_key = if options.sign_with_kid {
+            derive_signing_key(key, kid)?
+        } else {
+            derive_signing_key(key, &uuid_str)?
+        };
 
         // Step 2: Build header + payload
-        let header = json!({
-            "iat": now,
+        let mut header = json!({
             "alg": "HS256",
-            "typ": "JWT",
-            "kid": uuid_str,
+            "typ": options.typ,
+            "kid": kid,
         });
+        if options.include_iat_in_header {
+            header["iat"] = json!(now);
+        }
 
         // Build payload with user data
         let mut payload_json = json!({
             "userId": user.id,
             "userEmail": user.email,
-            "expires": expires,
         });
+        if options.expiry_claim != ExpiryClaim::Exp {
+            payload_json["expires"] = json!(expires);
+        }
+        if options.expiry_claim != ExpiryClaim::Expires {
+            payload_json["exp"] = json!(expires);
+        }
+
+        if options.include_iat_in_payload {
+            payload_json["iat"] = json!(now);
+        }
+
+        if options.promote_user_claims {
+            payload_json["email"] = json!(user.email);
+        }
 
         // Add name if present
         if let Some(ref user_name) = user.user_name {
@@ -170,67 +1195,500 @@ impl VortexClient {
             payload_json["adminScopes"] = json!(scopes);
         }
 
-        // Add allowedEmailDomains if present (for domain-restricted invitations)
-        if let Some(ref domains) = user.allowed_email_domains {
-            if !domains.is_empty() {
-                payload_json["allowedEmailDomains"] = json!(domains);
+        // Add allowedEmailDomains if present (for domain-restricted invitations)
+        if let Some(ref domains) = user.allowed_email_domains {
+            if !domains.is_empty() {
+                payload_json["allowedEmailDomains"] = json!(domains);
+            }
+        }
+
+        // Add any additional properties from extra parameter
+        if let Some(extra_props) = extra {
+            for (key, value) in extra_props {
+                payload_json[key] = value;
+            }
+        }
+
+        // Fill in client defaults for claims the caller did not provide
+        if payload_json.get("role").is_none() {
+            if let Some(ref role) = self.default_role {
+                payload_json["role"] = json!(role);
+            }
+        }
+        let has_identifiers = payload_json
+            .get("identifiers")
+            .and_then(|identifiers| identifiers.as_array())
+            .is_some_and(|identifiers| !identifiers.is_empty());
+        if !has_identifiers {
+            if let Some(ref identifiers) = self.default_identifiers {
+                payload_json["identifiers"] = json!(identifiers);
+            }
+        }
+
+        if let Some(role) = payload_json.get("role") {
+            self.validate_role(role)?;
+        }
+        if !options.allow_groups_without_id {
+            if let Some(groups) = payload_json.get("groups") {
+                check_group_ids(groups)?;
+            }
+        }
+
+        // Step 3: Base64URL encode header and payload
+        let header_bytes = serde_json::to_vec(&header)
+            .map_err(|e| VortexError::SerializationError(e.to_string()))?;
+        let payload_bytes = serde_json::to_vec(&payload_json)
+            .map_err(|e| VortexError::SerializationError(e.to_string()))?;
+        let header_b64 = URL_SAFE_NO_PAD.encode(header_bytes);
+        let payload_b64 = URL_SAFE_NO_PAD.encode(payload_bytes);
+
+        // Step 4: Sign with HMAC-SHA256
+        let to_sign = format!("{}.{}", header_b64, payload_b64);
+        let mut sig_hmac = HmacSha256::new_from_slice(signing_key.as_slice())
+            .map_err(|e| VortexError::CryptoError(format!("HMAC error: {}", e)))?;
+        sig_hmac.update(to_sign.as_bytes());
+        let signature = sig_hmac.finalize().into_bytes();
+        let sig_b64 = URL_SAFE_NO_PAD.encode(signature);
+
+        let token = format!("{}.{}.{}", header_b64, payload_b64, sig_b64);
+        if let Some(max) = self.max_token_bytes {
+            if token.len() > max {
+                return Err(VortexError::InvalidRequest(format!(
+                    "token exceeds {} bytes",
+                    max
+                )));
+            }
+        }
+
+        Ok(token)
+    }
+
+    /// Verify and decode a JWT signed with this client's API key
+    ///
+    /// Accepts tokens produced by `generate_jwt` or by any other Vortex SDK
+    /// using the same signing scheme (e.g., the Node.js SDK), including tokens
+    /// with a `kid` override ([`JwtOptions::with_kid`]). The signature is
+    /// verified with the key derived from the API key's UUID or, for a custom
+    /// `kid`, from that `kid`; the `expires` claim is not checked (see
+    /// `decode_jwt_with_leeway`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vortex_sdk::{VortexClient, User};
+    ///
+    /// let client = VortexClient::new("VRTX.AAAAAAAAAAAAAAAAAAAAAA.test_secret_key".to_string());
+    /// let user = User::new("user-123", "user@example.com");
+    /// let jwt = client.generate_jwt(&user, None).unwrap();
+    ///
+    /// let decoded = client.decode_jwt(&jwt).unwrap();
+    /// assert_eq!(decoded.payload["userId"], "user-123");
+    /// ```
+    pub fn decode_jwt(&self, token: &str) -> Result<DecodedJwt, VortexError> {
+        let parts: Vec<&str> = token.split('.').collect();
+        if parts.len() != 3 {
+            return Err(VortexError::InvalidRequest("Malformed JWT".to_string()));
+        }
+
+        let (uuid_str, key) = split_api_key(&self.api_key)?;
+
+        let header = decode_jwt_segment(parts[0])?;
+        let kid = header
+            .get("kid")
+            .and_then(|kid| kid.as_str())
+            .ok_or_else(|| VortexError::CryptoError("JWT has no kid".to_string()))?;
+
+        let signature = URL_SAFE_NO_PAD
+            .decode(parts[2])
+            .map_err(|e| VortexError::InvalidRequest(format!("Malformed JWT: {}", e)))?;
+
+        // A custom kid may or may not have been used to derive the key (`sign_with_kid`)
+        let mut key_ids = vec![uuid_str.as_str()];
+        if kid != uuid_str {
+            key_ids.push(kid);
+        }
+        let signed_input = format!("{}.{}", parts[0], parts[1]);
+        let mut verified = false;
+        for key_id in key_ids {
+            let signing_key = derive_signing_key(key, key_id)?;
+            let mut sig_hmac = HmacSha256::new_from_slice(signing_key.as_slice())
+                .map_err(|e| VortexError::CryptoError(format!("HMAC error: {}", e)))?;
+            sig_hmac.update(signed_input.as_bytes());
+            if sig_hmac.verify_slice(&signature).is_ok() {
+                verified = true;
+                break;
+            }
+        }
+        if !verified {
+            return Err(VortexError::CryptoError(
+                "JWT signature verification failed".to_string(),
+            ));
+        }
+
+        let payload = decode_jwt_segment(parts[1])?;
+
+        Ok(DecodedJwt { header, payload })
+    }
+
+    /// Verify and decode a JWT, rejecting it once `expires` has passed
+    ///
+    /// Performs the checks of `decode_jwt`, then treats the token as valid
+    /// while `expires + leeway >= now`, reading `exp` if `expires` is absent.
+    /// A small leeway (e.g. 30 seconds) tolerates clock drift between the
+    /// minting and verifying hosts. Tokens without a numeric `expires` or
+    /// `exp` claim are rejected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use vortex_sdk::{VortexClient, User};
+    ///
+    /// let client = VortexClient::new("VRTX.AAAAAAAAAAAAAAAAAAAAAA.test_secret_key".to_string());
+    /// let user = User::new("user-123", "user@example.com");
+    /// let jwt = client.generate_jwt(&user, None).unwrap();
+    ///
+    /// let decoded = client.decode_jwt_with_leeway(&jwt, Duration::from_secs(30)).unwrap();
+    /// assert_eq!(decoded.payload["userId"], "user-123");
+    /// ```
+    pub fn decode_jwt_with_leeway(
+        &self,
+        token: &str,
+        leeway: Duration,
+    ) -> Result<DecodedJwt, VortexError> {
+        let decoded = self.decode_jwt(token)?;
+
+        let expires = ["expires", "exp"]
+            .iter()
+            .find_map(|claim| decoded.payload.get(*claim).and_then(|expires| expires.as_u64()))
+            .ok_or_else(|| VortexError::CryptoError("JWT has no expires claim".to_string()))?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| VortexError::CryptoError(format!("System clock error: {}", e)))?
+            .as_secs();
+        if expires.saturating_add(leeway.as_secs()) < now {
+            return Err(VortexError::CryptoError("JWT has expired".to_string()));
+        }
+
+        Ok(decoded)
+    }
+
+    /// Get invitations by target (email or sms)
+    ///
+    /// Email values are normalized with [`normalize_email`] before being sent,
+    /// so lookups are case-insensitive. Phone values are normalized with
+    /// [`normalize_phone`], so formatting differences do not matter.
+    pub async fn get_invitations_by_target(
+        &self,
+        target_type: &str,
+        target_value: &str,
+    ) -> Result<Vec<Invitation>, VortexError> {
+        self.get_invitations_by_target_with_options(
+            target_type,
+            target_value,
+            &ListInvitationsOptions::default(),
+        )
+        .await
+    }
+
+    /// Get invitations by target (email or sms) with list filters
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use vortex_sdk::{ListInvitationsOptions, VortexClient};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = VortexClient::new("VRTX.xxx.yyy".to_string());
+    ///     let options = ListInvitationsOptions::new().with_modified_since("2026-01-01T00:00:00Z");
+    ///     let changed = client
+    ///         .get_invitations_by_target_with_options("email", "user@example.com", &options)
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_invitations_by_target_with_options(
+        &self,
+        target_type: &str,
+        target_value: &str,
+        options: &ListInvitationsOptions,
+    ) -> Result<Vec<Invitation>, VortexError> {
+        let target_value = normalize_target_value(target_type, target_value);
+        let mut params = HashMap::new();
+        params.insert("targetType", target_type);
+        params.insert("targetValue", target_value.as_str());
+        options.apply(&mut params);
+
+        self.list_invitations("/api/v1/invitations", Some(params), options.request_options())
+            .await
+    }
+
+    /// Get at most `max` invitations for a target (email or sms)
+    ///
+    /// A guardrail for accounts of untrusted size: the `limit` query parameter
+    /// is sent so the API can bound the response, and the result is truncated
+    /// to `max` in any case. `truncated` is set when more invitations exist.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use vortex_sdk::VortexClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = VortexClient::new("VRTX.xxx.yyy".to_string());
+    ///     let result = client
+    ///         .get_invitations_by_target_limited("email", "user@example.com", 100)
+    ///         .await?;
+    ///     if result.truncated {
+    ///         println!("Showing the first {} invitations", result.invitations.len());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_invitations_by_target_limited(
+        &self,
+        target_type: &str,
+        target_value: &str,
+        max: usize,
+    ) -> Result<LimitedInvitations, VortexError> {
+        // Ask for one extra so we can tell whether anything was cut off
+        let limit = max.saturating_add(1).to_string();
+        let target_value = normalize_target_value(target_type, target_value);
+        let mut params = HashMap::new();
+        params.insert("targetType", target_type);
+        params.insert("targetValue", target_value.as_str());
+        params.insert("limit", limit.as_str());
+
+        let mut invitations = self
+            .list_invitations("/api/v1/invitations", Some(params), RequestOptions::default())
+            .await?;
+
+        let truncated = invitations.len() > max;
+        invitations.truncate(max);
+
+        Ok(LimitedInvitations {
+            invitations,
+            truncated,
+        })
+    }
+
+    /// Get the distinct groups a target (email or sms) has been invited to
+    ///
+    /// Groups are derived from the target's invitations and deduplicated by
+    /// their Vortex id, in the order they first appear.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use vortex_sdk::VortexClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = VortexClient::new("VRTX.xxx.yyy".to_string());
+    ///     let groups = client.get_groups_by_target("email", "user@example.com").await?;
+    ///     for group in groups {
+    ///         println!("{} ({})", group.name, group.group_type);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_groups_by_target(
+        &self,
+        target_type: &str,
+        target_value: &str,
+    ) -> Result<Vec<InvitationGroup>, VortexError> {
+        let invitations = self
+            .get_invitations_by_target(target_type, target_value)
+            .await?;
+
+        let mut seen = HashSet::new();
+        let groups = invitations
+            .into_iter()
+            .flat_map(|invitation| invitation.groups)
+            .filter(|group| seen.insert(group.id.clone()))
+            .collect();
+
+        Ok(groups)
+    }
+
+    /// Get a specific invitation by ID
+    ///
+    /// Goes through the ETag cache when `with_invitation_cache` is enabled.
+    pub async fn get_invitation(&self, invitation_id: &str) -> Result<Invitation, VortexError> {
+        let path = format!("/api/v1/invitations/{}", invitation_id);
+        let Some(cache) = &self.invitation_cache else {
+            return self.api_request(Method::GET, &path, None::<&()>, None).await;
+        };
+
+        let runtime = tokio::runtime::Handle::try_current().ok();
+        let (generation, lookup) = cache.lookup(invitation_id, runtime.is_some());
+        let etag = match lookup {
+            CacheLookup::Hit(invitation) => return Ok(invitation),
+            CacheLookup::Stale(invitation, etag) => {
+                let mut options = RequestOptions::default();
+                if let Some(etag) = etag {
+                    options.headers.push(("If-None-Match", etag));
+                }
+                match (runtime, self.build_request(&Method::GET, &path, None::<&()>, None, options))
+                {
+                    (Some(runtime), Ok((request, _))) => {
+                        let transport = self.transport();
+                        let cache = Arc::clone(cache);
+                        let invitation_id = invitation_id.to_string();
+                        runtime.spawn(async move {
+                            let response = transport.execute(request).await.ok();
+                            cache.complete(&invitation_id, response, generation).await;
+                        });
+                    }
+                    _ => cache.end_revalidation(invitation_id),
+                }
+                return Ok(invitation);
             }
-        }
+            CacheLookup::Miss(etag) => etag,
+        };
 
-        // Add any additional properties from extra parameter
-        if let Some(extra_props) = extra {
-            for (key, value) in extra_props {
-                payload_json[key] = value;
+        match self.get_invitation_conditional(invitation_id, etag.as_deref()).await? {
+            ConditionalResponse::Modified { value, etag } => {
+                cache.insert(invitation_id, value.clone(), etag, generation);
+                Ok(value)
+            }
+            ConditionalResponse::NotModified { etag } => {
+                match cache.touch(invitation_id, etag, generation) {
+                    Some(invitation) => Ok(invitation),
+                    // Cleared by a mutating request while the 304 was in flight
+                    None => self.api_request(Method::GET, &path, None::<&()>, None).await,
+                }
             }
         }
-
-        // Step 3: Base64URL encode header and payload
-        let header_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header).unwrap());
-        let payload_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&payload_json).unwrap());
-
-        // Step 4: Sign with HMAC-SHA256
-        let to_sign = format!("{}.{}", header_b64, payload_b64);
-        let mut sig_hmac = HmacSha256::new_from_slice(&signing_key)
-            .map_err(|e| VortexError::CryptoError(format!("HMAC error: {}", e)))?;
-        sig_hmac.update(to_sign.as_bytes());
-        let signature = sig_hmac.finalize().into_bytes();
-        let sig_b64 = URL_SAFE_NO_PAD.encode(&signature);
-
-        Ok(format!("{}.{}.{}", header_b64, payload_b64, sig_b64))
     }
 
-    /// Get invitations by target (email or sms)
-    pub async fn get_invitations_by_target(
+    /// Fetch an invitation from the API, skipping the invitation cache
+    ///
+    /// For reads that decide what to do next, where a stale copy would be wrong.
+    async fn get_invitation_uncached(
         &self,
-        target_type: &str,
-        target_value: &str,
-    ) -> Result<Vec<Invitation>, VortexError> {
-        let mut params = HashMap::new();
-        params.insert("targetType", target_type);
-        params.insert("targetValue", target_value);
+        invitation_id: &str,
+    ) -> Result<Invitation, VortexError> {
+        self.api_request(
+            Method::GET,
+            &format!("/api/v1/invitations/{}", invitation_id),
+            None::<&()>,
+            None,
+        )
+        .await
+    }
 
-        let response: InvitationsResponse = self
-            .api_request("GET", "/api/v1/invitations", None::<&()>, Some(params))
-            .await?;
+    /// Get selected fields of an invitation as raw JSON
+    ///
+    /// Sends the field names as a comma-separated `fields` query parameter so
+    /// the API can return a partial invitation. Use `get_invitation` when you
+    /// need the typed struct.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use vortex_sdk::VortexClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = VortexClient::new("VRTX.xxx.yyy".to_string());
+    ///     let partial = client.get_invitation_fields("inv-123", &["status", "accepts"]).await?;
+    ///     println!("Status: {}", partial["status"]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_invitation_fields(
+        &self,
+        invitation_id: &str,
+        fields: &[&str],
+    ) -> Result<serde_json::Value, VortexError> {
+        if fields.is_empty() {
+            return Err(VortexError::InvalidRequest(
+                "At least one field must be requested".to_string(),
+            ));
+        }
 
-        Ok(response.invitations.unwrap_or_default())
-    }
+        let fields = fields.join(",");
+        let mut params = HashMap::new();
+        params.insert("fields", fields.as_str());
 
-    /// Get a specific invitation by ID
-    pub async fn get_invitation(&self, invitation_id: &str) -> Result<Invitation, VortexError> {
         self.api_request(
-            "GET",
+            Method::GET,
             &format!("/api/v1/invitations/{}", invitation_id),
             None::<&()>,
-            None,
+            Some(params),
         )
         .await
     }
 
+    /// Get a specific invitation by ID, unless it matches a known ETag
+    ///
+    /// When `etag` is given it is sent as `If-None-Match`; a `304 Not Modified`
+    /// response yields `ConditionalResponse::NotModified` without a body. The
+    /// response ETag, if any, is returned so it can be passed to the next call.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use vortex_sdk::{ConditionalResponse, VortexClient};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = VortexClient::new("VRTX.xxx.yyy".to_string());
+    ///     let mut etag = None;
+    ///     match client.get_invitation_conditional("inv-123", etag.as_deref()).await? {
+    ///         ConditionalResponse::Modified { value, etag: new_etag } => {
+    ///             println!("Changed: {}", value);
+    ///             etag = new_etag;
+    ///         }
+    ///         ConditionalResponse::NotModified { .. } => println!("Unchanged"),
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_invitation_conditional(
+        &self,
+        invitation_id: &str,
+        etag: Option<&str>,
+    ) -> Result<ConditionalResponse<Invitation>, VortexError> {
+        let mut options = RequestOptions::default();
+        if let Some(etag) = etag {
+            options.headers.push(("If-None-Match", etag.to_string()));
+        }
+
+        let (response, request_url) = self
+            .execute(
+                Method::GET,
+                &format!("/api/v1/invitations/{}", invitation_id),
+                None::<&()>,
+                None,
+                options,
+            )
+            .await?;
+
+        let response_etag = response_etag(&response);
+
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            return Ok(ConditionalResponse::NotModified {
+                etag: response_etag.or_else(|| etag.map(|e| e.to_string())),
+            });
+        }
+
+        let value = self
+            .read_response(response, &Method::GET, &request_url)
+            .await?;
+        Ok(ConditionalResponse::Modified {
+            value,
+            etag: response_etag,
+        })
+    }
+
     /// Revoke (delete) an invitation
     pub async fn revoke_invitation(&self, invitation_id: &str) -> Result<(), VortexError> {
         self.api_request::<(), ()>(
-            "DELETE",
+            Method::DELETE,
             &format!("/api/v1/invitations/{}", invitation_id),
             None,
             None,
@@ -239,6 +1697,96 @@ impl VortexClient {
         Ok(())
     }
 
+    /// Revoke (delete) an invitation and return the revoked invitation
+    ///
+    /// Use this when the API echoes the soft-deleted invitation and you need
+    /// fields such as `deactivated` or `modified_at`. Use `revoke_invitation`
+    /// if you don't need the response body.
+    pub async fn revoke_invitation_returning(
+        &self,
+        invitation_id: &str,
+    ) -> Result<Invitation, VortexError> {
+        self.api_request(
+            Method::DELETE,
+            &format!("/api/v1/invitations/{}", invitation_id),
+            None::<&()>,
+            None,
+        )
+        .await
+    }
+
+    /// Deactivate an invitation without deleting it
+    ///
+    /// Sends `PATCH /api/v1/invitations/{id}` with `{"deactivated": true}`.
+    /// Unlike `revoke_invitation`, the record and its engagement counters
+    /// (`click_throughs`, `views`) are kept for reporting. Returns the
+    /// updated invitation.
+    pub async fn deactivate_invitation(
+        &self,
+        invitation_id: &str,
+    ) -> Result<Invitation, VortexError> {
+        self.api_request(
+            Method::PATCH,
+            &format!("/api/v1/invitations/{}", invitation_id),
+            Some(&json!({ "deactivated": true })),
+            None,
+        )
+        .await
+    }
+
+    /// Revoke all pending invitations for a target (email or sms)
+    ///
+    /// Lists the invitations for the target and revokes each one that is
+    /// still pending ([`Invitation::is_pending`]) and not expired. Accepted,
+    /// revoked and expired invitations are left alone and appear in neither
+    /// list. A failed revoke does not stop the others; check `failed` to see
+    /// which invitations are left.
+    ///
+    /// Returns a [`BulkResult`] rather than a revoked count so that failures
+    /// are reported; the count is `result.succeeded.len()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if the invitations cannot be listed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use vortex_sdk::VortexClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = VortexClient::new("VRTX.xxx.yyy".to_string());
+    ///     let result = client
+    ///         .revoke_invitations_by_target("email", "user@example.com")
+    ///         .await?;
+    ///     println!("{} revoked, {} failed", result.succeeded.len(), result.failed.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn revoke_invitations_by_target(
+        &self,
+        target_type: &str,
+        target_value: &str,
+    ) -> Result<BulkResult, VortexError> {
+        let invitations = self
+            .get_invitations_by_target(target_type, target_value)
+            .await?;
+
+        let mut result = BulkResult::default();
+        for invitation in invitations {
+            if !invitation.is_pending() || invitation.is_expired() {
+                continue;
+            }
+            match self.revoke_invitation(&invitation.id).await {
+                Ok(()) => result.succeeded.push(invitation),
+                Err(e) => result.failed.push((invitation.id, e)),
+            }
+        }
+
+        Ok(result)
+    }
+
     /// Accept multiple invitations
     ///
     /// # Arguments
@@ -273,9 +1821,50 @@ impl VortexClient {
         &self,
         invitation_ids: Vec<String>,
         param: impl Into<crate::types::AcceptInvitationParam>,
+    ) -> Result<Invitation, VortexError> {
+        self.accept_invitations_with_attributes(invitation_ids, param, HashMap::new())
+            .await
+    }
+
+    /// Accept multiple invitations, recording attributes on the acceptance
+    ///
+    /// `attributes` are merged into the request body so the server stores them
+    /// on the `InvitationAcceptance` atomically with the accept. Keys that
+    /// collide with the request fields (`invitationIds`, `user`, `target`) are
+    /// rejected with `VortexError::InvalidRequest`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use vortex_sdk::{VortexClient, AcceptUser};
+    ///
+    /// # async fn example() {
+    /// let client = VortexClient::new("VRTX.key.secret".to_string());
+    /// let user = AcceptUser::new().with_email("user@example.com");
+    /// let mut attributes = HashMap::new();
+    /// attributes.insert("plan".to_string(), serde_json::json!("pro"));
+    /// let result = client
+    ///     .accept_invitations_with_attributes(vec!["inv-123".to_string()], user, attributes)
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn accept_invitations_with_attributes(
+        &self,
+        invitation_ids: Vec<String>,
+        param: impl Into<crate::types::AcceptInvitationParam>,
+        attributes: HashMap<String, serde_json::Value>,
     ) -> Result<Invitation, VortexError> {
         use crate::types::{AcceptInvitationParam, AcceptUser};
 
+        const RESERVED_KEYS: [&str; 3] = ["invitationIds", "user", "target"];
+        if let Some(key) = attributes.keys().find(|k| RESERVED_KEYS.contains(&k.as_str())) {
+            return Err(VortexError::InvalidRequest(format!(
+                "Attribute key `{}` is reserved",
+                key
+            )));
+        }
+
         let param = param.into();
 
         // Convert all parameter types to User format to avoid async recursion
@@ -298,7 +1887,13 @@ impl VortexClient {
                         _ => AcceptUser::new().with_email(&target.value),
                     };
 
-                    match Box::pin(self.accept_invitations(invitation_ids.clone(), user)).await {
+                    let result = Box::pin(self.accept_invitations_with_attributes(
+                        invitation_ids.clone(),
+                        user,
+                        attributes.clone(),
+                    ))
+                    .await;
+                    match result {
                         Ok(result) => last_result = Some(result),
                         Err(e) => last_error = Some(e),
                     }
@@ -330,12 +1925,15 @@ impl VortexClient {
             ));
         }
 
-        let body = json!({
+        let mut body = json!({
             "invitationIds": invitation_ids,
             "user": user,
         });
+        for (key, value) in attributes {
+            body[key] = value;
+        }
 
-        self.api_request("POST", "/api/v1/invitations/accept", Some(&body), None)
+        self.api_request(Method::POST, "/api/v1/invitations/accept", Some(&body), None)
             .await
     }
 
@@ -345,77 +1943,391 @@ impl VortexClient {
     ///
     /// # Arguments
     ///
-    /// * `invitation_id` - Single invitation ID to accept
-    /// * `user` - User object with email and/or phone
+    /// * `invitation_id` - Single invitation ID to accept
+    /// * `user` - User data (preferred) or legacy target format, validated
+    ///   exactly as in `accept_invitations`
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Invitation, VortexError>` - The accepted invitation result
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vortex_sdk::{VortexClient, AcceptUser};
+    ///
+    /// # async fn example() {
+    /// let client = VortexClient::new("VRTX.key.secret".to_string());
+    /// let user = AcceptUser::new().with_email("user@example.com");
+    /// let result = client.accept_invitation("inv-123", user).await;
+    /// # }
+    /// ```
+    pub async fn accept_invitation(
+        &self,
+        invitation_id: &str,
+        user: impl Into<crate::types::AcceptInvitationParam>,
+    ) -> Result<Invitation, VortexError> {
+        self.accept_invitations(vec![invitation_id.to_string()], user).await
+    }
+
+    /// Accept a single invitation and report whether the acceptance is new
+    ///
+    /// The API reports success for an invitation the user has already
+    /// accepted, so the invitation is fetched first (bypassing the invitation
+    /// cache) and `newly_accepted` is false if its `accepts` already include
+    /// the user's email or phone. Use this to trigger one-time side effects
+    /// such as welcome emails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vortex_sdk::{VortexClient, AcceptUser};
+    ///
+    /// # async fn example() -> Result<(), vortex_sdk::VortexError> {
+    /// let client = VortexClient::new("VRTX.key.secret".to_string());
+    /// let user = AcceptUser::new().with_email("user@example.com");
+    /// let result = client.accept_invitation_with_result("inv-123", user).await?;
+    /// if result.newly_accepted {
+    ///     println!("Send welcome email for {}", result.invitation.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn accept_invitation_with_result(
+        &self,
+        invitation_id: &str,
+        user: crate::types::AcceptUser,
+    ) -> Result<AcceptResult, VortexError> {
+        let before = self.get_invitation_uncached(invitation_id).await?;
+        let already_accepted = is_accepted_by(&before, &user);
+
+        let invitation = self.accept_invitation(invitation_id, user).await?;
+
+        Ok(AcceptResult {
+            invitation,
+            newly_accepted: !already_accepted,
+        })
+    }
+
+    /// Accept multiple invitations and return every affected invitation
+    ///
+    /// Unlike `accept_invitations`, this returns the full set of invitations
+    /// updated by the server. If the API responds with a single invitation,
+    /// it is wrapped in a vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vortex_sdk::{VortexClient, AcceptUser};
+    ///
+    /// # async fn example() {
+    /// let client = VortexClient::new("VRTX.key.secret".to_string());
+    /// let user = AcceptUser::new().with_email("user@example.com");
+    /// let ids = vec!["inv-123".to_string(), "inv-456".to_string()];
+    /// let result = client.accept_invitations_multi(ids, user).await;
+    /// # }
+    /// ```
+    pub async fn accept_invitations_multi(
+        &self,
+        invitation_ids: Vec<String>,
+        user: crate::types::AcceptUser,
+    ) -> Result<Vec<Invitation>, VortexError> {
+        if user.email.is_none() && user.phone.is_none() {
+            return Err(VortexError::InvalidRequest(
+                "User must have either email or phone".to_string(),
+            ));
+        }
+
+        let body = json!({
+            "invitationIds": invitation_ids,
+            "user": user,
+        });
+
+        let response: OneOrMany<Invitation> = self
+            .api_request(Method::POST, "/api/v1/invitations/accept", Some(&body), None)
+            .await?;
+
+        Ok(response.into_vec())
+    }
+
+    /// Delete all invitations for a specific group
+    ///
+    /// Returns what the API reported about the deletion, so partial failures
+    /// can be reconciled. See [`DeleteResult`].
+    pub async fn delete_invitations_by_group(
+        &self,
+        group_type: &str,
+        group_id: &str,
+    ) -> Result<DeleteResult, VortexError> {
+        self.api_request(
+            Method::DELETE,
+            &format!("/api/v1/invitations/by-group/{}/{}", group_type, group_id),
+            None::<&()>,
+            None,
+        )
+        .await
+    }
+
+    /// Delete all invitations for a specific group, after checking how many there are
+    ///
+    /// With `confirm_count` set, the group's invitations are listed first
+    /// (bypassing the invitation cache) and nothing is deleted unless their
+    /// number matches. This guards ops scripts against deleting far more than
+    /// intended. Invitations created between the check and the delete are
+    /// still deleted.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// * `Result<Invitation, VortexError>` - The accepted invitation result
+    /// Returns `VortexError::InvalidRequest` if the count does not match.
     ///
     /// # Example
     ///
-    /// ```
-    /// use vortex_sdk::{VortexClient, AcceptUser};
+    /// ```no_run
+    /// use vortex_sdk::{DeleteOptions, VortexClient};
     ///
-    /// # async fn example() {
-    /// let client = VortexClient::new("VRTX.key.secret".to_string());
-    /// let user = AcceptUser::new().with_email("user@example.com");
-    /// let result = client.accept_invitation("inv-123", user).await;
-    /// # }
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = VortexClient::new("VRTX.xxx.yyy".to_string());
+    ///     client
+    ///         .delete_invitations_by_group_with_options(
+    ///             "workspace",
+    ///             "workspace-123",
+    ///             &DeleteOptions::new().with_confirm_count(12),
+    ///         )
+    ///         .await?;
+    ///     Ok(())
+    /// }
     /// ```
-    pub async fn accept_invitation(
+    pub async fn delete_invitations_by_group_with_options(
         &self,
-        invitation_id: &str,
-        user: crate::types::AcceptUser,
-    ) -> Result<Invitation, VortexError> {
-        self.accept_invitations(vec![invitation_id.to_string()], user).await
+        group_type: &str,
+        group_id: &str,
+        options: &DeleteOptions,
+    ) -> Result<DeleteResult, VortexError> {
+        if let Some(expected) = options.confirm_count {
+            let response: InvitationsResponse = self
+                .api_request(
+                    Method::GET,
+                    &format!("/api/v1/invitations/by-group/{}/{}", group_type, group_id),
+                    None::<&()>,
+                    None,
+                )
+                .await?;
+            let actual = response.into_iter().count();
+            if actual != expected {
+                return Err(VortexError::InvalidRequest(format!(
+                    "Refusing to delete invitations for group {}/{}: found {}, expected {}",
+                    group_type, group_id, actual, expected
+                )));
+            }
+        }
+
+        self.delete_invitations_by_group(group_type, group_id).await
     }
 
-    /// Delete all invitations for a specific group
-    pub async fn delete_invitations_by_group(
+    /// Get all invitations for a specific group
+    pub async fn get_invitations_by_group(
         &self,
         group_type: &str,
         group_id: &str,
-    ) -> Result<(), VortexError> {
-        self.api_request::<(), ()>(
-            "DELETE",
-            &format!("/api/v1/invitations/by-group/{}/{}", group_type, group_id),
-            None,
-            None,
+    ) -> Result<Vec<Invitation>, VortexError> {
+        self.get_invitations_by_group_with_options(
+            group_type,
+            group_id,
+            &ListInvitationsOptions::default(),
         )
-        .await?;
-        Ok(())
+        .await
     }
 
-    /// Get all invitations for a specific group
-    pub async fn get_invitations_by_group(
+    /// Get invitations for a specific group with list filters
+    pub async fn get_invitations_by_group_with_options(
         &self,
         group_type: &str,
         group_id: &str,
+        options: &ListInvitationsOptions,
     ) -> Result<Vec<Invitation>, VortexError> {
-        let response: InvitationsResponse = self
-            .api_request(
-                "GET",
-                &format!("/api/v1/invitations/by-group/{}/{}", group_type, group_id),
-                None::<&()>,
-                None,
-            )
-            .await?;
+        let mut params = HashMap::new();
+        options.apply(&mut params);
+
+        self.list_invitations(
+            &format!("/api/v1/invitations/by-group/{}/{}", group_type, group_id),
+            Some(params),
+            options.request_options(),
+        )
+        .await
+    }
+
+    /// Get all invitations for a specific group, bucketed by status
+    ///
+    /// Statuses with no invitations are absent from the map. Within a bucket,
+    /// invitations keep the order returned by the API.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use vortex_sdk::{InvitationStatus, VortexClient};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = VortexClient::new("VRTX.xxx.yyy".to_string());
+    ///     let by_status = client
+    ///         .get_invitations_by_group_grouped("workspace", "workspace-123")
+    ///         .await?;
+    ///     let pending = by_status.get(&InvitationStatus::Delivered).map_or(0, Vec::len);
+    ///     println!("{} delivered, not yet accepted", pending);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_invitations_by_group_grouped(
+        &self,
+        group_type: &str,
+        group_id: &str,
+    ) -> Result<HashMap<InvitationStatus, Vec<Invitation>>, VortexError> {
+        let invitations = self.get_invitations_by_group(group_type, group_id).await?;
 
-        Ok(response.invitations.unwrap_or_default())
+        let mut grouped: HashMap<InvitationStatus, Vec<Invitation>> = HashMap::new();
+        for invitation in invitations {
+            grouped
+                .entry(invitation.status.clone())
+                .or_default()
+                .push(invitation);
+        }
+        Ok(grouped)
     }
 
     /// Reinvite a user (send invitation again)
     pub async fn reinvite(&self, invitation_id: &str) -> Result<Invitation, VortexError> {
-        self.api_request(
-            "POST",
+        self.reinvite_with_options(invitation_id, RequestOptions::default())
+            .await
+    }
+
+    /// Reinvite a user unless the invitation changed within `min_interval`
+    ///
+    /// Fetches the invitation first (bypassing the invitation cache) and uses
+    /// the later of `modified_at` and `created_at` as the time it was last
+    /// sent. If that is less than `min_interval` ago, returns
+    /// `VortexError::InvalidRequest("reinvited too recently")` without sending.
+    /// Guards against duplicate invitations from retry loops; it is a
+    /// client-side check, so concurrent callers can still race.
+    ///
+    /// The API does not report when the last reminder went out, so this is an
+    /// approximation: any change to the invitation (metadata, status, an
+    /// unrelated edit) moves `modified_at` and restarts the interval, even if
+    /// nothing was sent.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use vortex_sdk::VortexClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = VortexClient::new("VRTX.xxx.yyy".to_string());
+    ///     let invitation = client
+    ///         .reinvite_guarded("inv-123", Duration::from_secs(3600))
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn reinvite_guarded(
+        &self,
+        invitation_id: &str,
+        min_interval: Duration,
+    ) -> Result<Invitation, VortexError> {
+        let invitation = self.get_invitation_uncached(invitation_id).await?;
+        if sent_within(&invitation, min_interval, SystemTime::now()) {
+            return Err(VortexError::InvalidRequest(
+                "reinvited too recently".to_string(),
+            ));
+        }
+
+        self.reinvite(invitation_id).await
+    }
+
+    /// Reinvite a user with per-call options
+    ///
+    /// Use [`RequestOptions::with_locale`] to deliver the invitation in the
+    /// recipient's language.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use vortex_sdk::{RequestOptions, VortexClient};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = VortexClient::new("VRTX.xxx.yyy".to_string());
+    ///     let options = RequestOptions::new().with_locale("fr-CA");
+    ///     let invitation = client.reinvite_with_options("inv-123", options).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn reinvite_with_options(
+        &self,
+        invitation_id: &str,
+        options: RequestOptions,
+    ) -> Result<Invitation, VortexError> {
+        self.api_request_with_options(
+            Method::POST,
             &format!("/api/v1/invitations/{}/reinvite", invitation_id),
             None::<&()>,
             None,
+            options,
         )
         .await
     }
 
+    /// Reinvite everyone in a group who has not yet accepted
+    ///
+    /// Lists the group's invitations, keeps those that are pending (see
+    /// [`Invitation::is_pending`]) and reinvites each, with at most five
+    /// requests in flight. A failed reinvite does not stop the
+    /// others; it is reported in [`BulkResult::failed`]. Only an error listing
+    /// the group fails the whole call.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use vortex_sdk::VortexClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = VortexClient::new("VRTX.xxx.yyy".to_string());
+    ///     let result = client.reinvite_pending_by_group("team", "team-123").await?;
+    ///     for (id, error) in &result.failed {
+    ///         eprintln!("Could not reinvite {}: {}", id, error);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn reinvite_pending_by_group(
+        &self,
+        group_type: &str,
+        group_id: &str,
+    ) -> Result<BulkResult, VortexError> {
+        let invitations = self.get_invitations_by_group(group_type, group_id).await?;
+
+        let outcomes: Vec<(String, Result<Invitation, VortexError>)> =
+            stream::iter(invitations.into_iter().filter(Invitation::is_pending))
+                .map(|invitation| async move {
+                    let result = self.reinvite(&invitation.id).await;
+                    (invitation.id, result)
+                })
+                .buffer_unordered(REINVITE_CONCURRENCY)
+                .collect()
+                .await;
+
+        let mut result = BulkResult::default();
+        for (id, outcome) in outcomes {
+            match outcome {
+                Ok(invitation) => result.succeeded.push(invitation),
+                Err(e) => result.failed.push((id, e)),
+            }
+        }
+        Ok(result)
+    }
+
     /// Create an invitation from your backend
     ///
     /// This method allows you to create invitations programmatically using your API key,
@@ -467,10 +2379,95 @@ impl VortexClient {
         &self,
         request: &CreateInvitationRequest,
     ) -> Result<CreateInvitationResponse, VortexError> {
-        self.api_request("POST", "/api/v1/invitations", Some(request), None)
+        self.create_invitation_with_options(request, RequestOptions::default())
             .await
     }
 
+    /// Create an invitation with per-call options
+    ///
+    /// Use [`RequestOptions::with_locale`] to deliver the invitation in the
+    /// recipient's language.
+    pub async fn create_invitation_with_options(
+        &self,
+        request: &CreateInvitationRequest,
+        options: RequestOptions,
+    ) -> Result<CreateInvitationResponse, VortexError> {
+        self.api_request_with_options(
+            Method::POST,
+            "/api/v1/invitations",
+            Some(request),
+            None,
+            options,
+        )
+        .await
+    }
+
+    /// Create an invitation at most once per idempotency key
+    ///
+    /// The key is sent as the `Idempotency-Key` header. Retrying with the same
+    /// key returns the invitation created by the first request instead of a
+    /// duplicate, which the API signals with `Idempotent-Replayed: true`.
+    /// `created` is only false when that header is present; a replay without
+    /// it is reported as created. Derive the key from something stable across
+    /// retries, such as a job id.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VortexError::InvalidRequest` if the key is empty.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use vortex_sdk::{CreateInvitationRequest, CreateInvitationTarget, Inviter, VortexClient};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = VortexClient::new("VRTX.xxx.yyy".to_string());
+    ///     let request = CreateInvitationRequest::new(
+    ///         "widget-config-123",
+    ///         CreateInvitationTarget::email("invitee@example.com"),
+    ///         Inviter::new("user-456"),
+    ///     );
+    ///
+    ///     let result = client.create_invitation_idempotent(&request, "job-8812").await?;
+    ///     if !result.created {
+    ///         println!("Already created by an earlier attempt: {}", result.invitation.id);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_invitation_idempotent(
+        &self,
+        request: &CreateInvitationRequest,
+        idempotency_key: impl Into<String>,
+    ) -> Result<CreateResult, VortexError> {
+        let idempotency_key = idempotency_key.into();
+        if idempotency_key.is_empty() {
+            return Err(VortexError::InvalidRequest(
+                "Idempotency key must not be empty".to_string(),
+            ));
+        }
+        let options = RequestOptions::new().with_idempotency_key(idempotency_key);
+
+        let (invitation, headers) = self
+            .api_request_with_headers(
+                Method::POST,
+                "/api/v1/invitations",
+                Some(request),
+                None,
+                options,
+            )
+            .await?;
+        let replayed = headers
+            .get("Idempotent-Replayed")
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.eq_ignore_ascii_case("true"));
+        Ok(CreateResult {
+            invitation,
+            created: !replayed,
+        })
+    }
+
     /// Get autojoin domains configured for a specific scope
     ///
     /// # Arguments
@@ -509,7 +2506,7 @@ impl VortexClient {
             "/api/v1/invitations/by-scope/{}/{}/autojoin",
             encoded_scope_type, encoded_scope
         );
-        self.api_request::<AutojoinDomainsResponse, ()>("GET", &path, None, None)
+        self.api_request::<AutojoinDomainsResponse, ()>(Method::GET, &path, None, None)
             .await
     }
 
@@ -544,81 +2541,437 @@ impl VortexClient {
     ///     )
     ///     .with_scope_name("Acme Corporation");
     ///
-    ///     let result = client.configure_autojoin(&request).await?;
+    ///     let result = client.configure_autojoin(&request).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// Sync an internal invitation action (accept or decline)
+    ///
+    /// This method notifies Vortex that an internal invitation was accepted or declined
+    /// within your application, so Vortex can update the invitation status accordingly.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use vortex_sdk::{VortexClient, SyncInternalInvitationRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = VortexClient::new("VRTX.xxx.yyy".to_string());
+    ///     let request = SyncInternalInvitationRequest::new(
+    ///         "user-123", "user-456", "accepted", "component-uuid",
+    ///     );
+    ///     let result = client.sync_internal_invitation(&request).await?;
+    ///     println!("Processed {} invitations", result.processed);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn sync_internal_invitation(
+        &self,
+        request: &SyncInternalInvitationRequest,
+    ) -> Result<SyncInternalInvitationResponse, VortexError> {
+        self.api_request(
+            Method::POST,
+            "/api/v1/invitations/sync-internal-invitation",
+            Some(request),
+            None,
+        )
+        .await
+    }
+
+    pub async fn configure_autojoin(
+        &self,
+        request: &ConfigureAutojoinRequest,
+    ) -> Result<AutojoinDomainsResponse, VortexError> {
+        self.api_request(Method::POST, "/api/v1/invitations/autojoin", Some(request), None)
+            .await
+    }
+
+    /// Check that the Vortex API is reachable and accepts the API key
+    ///
+    /// Sends an authenticated `HEAD` request, which transfers no body. Intended
+    /// for readiness probes. Any response other than the errors below means
+    /// the API was reached and the key was accepted.
+    ///
+    /// # Errors
+    ///
+    /// * `VortexError::InvalidApiKey` if the API responds with 401 or 403
+    /// * `VortexError::ApiError` if the API responds with a server error
+    /// * `VortexError::HttpError` if the API cannot be reached
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use vortex_sdk::VortexClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = VortexClient::new("VRTX.xxx.yyy".to_string());
+    ///     let ready = client.ping().await.is_ok();
+    ///     println!("ready: {}", ready);
+    /// }
+    /// ```
+    pub async fn ping(&self) -> Result<(), VortexError> {
+        let (response, request_url) = self
+            .execute(
+                Method::HEAD,
+                "/api/v1/invitations",
+                None::<&()>,
+                None,
+                RequestOptions::default(),
+            )
+            .await?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+            return Err(VortexError::InvalidApiKey(format!(
+                "API key rejected: {} (HEAD {})",
+                status, request_url
+            )));
+        }
+        if status.is_server_error() {
+            return Err(VortexError::ApiError(format!(
+                "API request failed: {} (HEAD {}){}",
+                status,
+                request_url,
+                request_id_note(&response)
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// The base URL requests are sent to
+    ///
+    /// Resolved from `VORTEX_API_BASE_URL` when the client is created with `new`.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Shut the client down, releasing its pooled connections
+    ///
+    /// Taking `self` by value guarantees no request made through this client is
+    /// still in flight. The HTTP client and its idle keep-alive connections are
+    /// dropped, and the current task yields once so the runtime can close them
+    /// before you continue your own teardown. Useful in short-lived (FaaS)
+    /// processes; a client shared through an `Arc` is shut down when the last
+    /// reference is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use vortex_sdk::VortexClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = VortexClient::new("VRTX.xxx.yyy".to_string());
+    ///     let invitation = client.get_invitation("inv-123").await?;
+    ///     client.shutdown().await;
     ///     Ok(())
     /// }
     /// ```
+    pub async fn shutdown(self) {
+        drop(self);
+        tokio::task::yield_now().await;
+    }
+
+    /// Replace any occurrence of the API key in `text` so it is safe to surface in errors
+    fn redact(&self, text: &str) -> String {
+        if self.api_key.is_empty() {
+            return text.to_string();
+        }
+        text.replace(self.api_key.as_str(), "[REDACTED]")
+    }
+
+    /// Fetch a list of invitations, going through the cache when enabled
+    async fn list_invitations(
+        &self,
+        path: &str,
+        query_params: Option<HashMap<&str, &str>>,
+        options: RequestOptions,
+    ) -> Result<Vec<Invitation>, VortexError> {
+        let cache_key = self.cache.as_ref().map(|_| {
+            let mut params: Vec<String> = query_params
+                .iter()
+                .flatten()
+                .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(v)))
+                .collect();
+            params.sort();
+            format!("{}?{}", path, params.join("&"))
+        });
+
+        if let (Some(cache), Some(key)) = (&self.cache, &cache_key) {
+            if let Some(invitations) = cache.get(key) {
+                return Ok(invitations);
+            }
+        }
+        let generation = self.cache.as_ref().map_or(0, InvitationCache::generation);
+
+        let response: InvitationsResponse = self
+            .api_request_with_options(Method::GET, path, None::<&()>, query_params, options)
+            .await?;
+        let invitations = response.into_vec();
+
+        if let (Some(cache), Some(key)) = (&self.cache, cache_key) {
+            cache.insert(key, invitations.clone(), generation);
+        }
+
+        Ok(invitations)
+    }
+
+    /// Send an arbitrary request to the Vortex API and parse the JSON response
     ///
-    /// Sync an internal invitation action (accept or decline)
-    ///
-    /// This method notifies Vortex that an internal invitation was accepted or declined
-    /// within your application, so Vortex can update the invitation status accordingly.
+    /// An escape hatch for endpoints the SDK does not wrap yet. `path` is
+    /// relative to the base URL and may include a query string. Requests go
+    /// through the same authentication, dry-run, cache invalidation and
+    /// circuit breaker handling as the typed methods.
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use vortex_sdk::{VortexClient, SyncInternalInvitationRequest};
+    /// use std::time::Duration;
+    /// use vortex_sdk::{Invitation, Method, RequestOptions, VortexClient};
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = VortexClient::new("VRTX.xxx.yyy".to_string());
-    ///     let request = SyncInternalInvitationRequest::new(
-    ///         "user-123", "user-456", "accepted", "component-uuid",
-    ///     );
-    ///     let result = client.sync_internal_invitation(&request).await?;
-    ///     println!("Processed {} invitations", result.processed);
+    ///     let options = RequestOptions::new().with_timeout(Duration::from_secs(2));
+    ///     let invitation: Invitation = client
+    ///         .request(Method::GET, "/api/v1/invitations/inv-123", None::<&()>, options)
+    ///         .await?;
     ///     Ok(())
     /// }
     /// ```
-    pub async fn sync_internal_invitation(
+    pub async fn request<T, B>(
         &self,
-        request: &SyncInternalInvitationRequest,
-    ) -> Result<SyncInternalInvitationResponse, VortexError> {
-        self.api_request(
-            "POST",
-            "/api/v1/invitations/sync-internal-invitation",
-            Some(request),
-            None,
-        )
-        .await
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+        options: RequestOptions,
+    ) -> Result<T, VortexError>
+    where
+        T: serde::de::DeserializeOwned,
+        B: serde::Serialize,
+    {
+        self.api_request_with_options(method, path, body, None, options)
+            .await
     }
 
-    pub async fn configure_autojoin(
+    /// Send a request to the Vortex API and parse the JSON response
+    ///
+    /// Any HTTP method is accepted, including `PATCH` for partial updates.
+    /// Every method other than `GET` and `HEAD` is treated as mutating: it is skipped in
+    /// dry-run mode and invalidates the invitation cache.
+    async fn api_request<T, B>(
         &self,
-        request: &ConfigureAutojoinRequest,
-    ) -> Result<AutojoinDomainsResponse, VortexError> {
-        self.api_request("POST", "/api/v1/invitations/autojoin", Some(request), None)
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+        query_params: Option<HashMap<&str, &str>>,
+    ) -> Result<T, VortexError>
+    where
+        T: serde::de::DeserializeOwned,
+        B: serde::Serialize,
+    {
+        self.api_request_with_options(method, path, body, query_params, RequestOptions::default())
             .await
     }
 
-    async fn api_request<T, B>(
+    async fn api_request_with_options<T, B>(
         &self,
-        method: &str,
+        method: Method,
         path: &str,
         body: Option<&B>,
         query_params: Option<HashMap<&str, &str>>,
+        options: RequestOptions,
     ) -> Result<T, VortexError>
     where
         T: serde::de::DeserializeOwned,
         B: serde::Serialize,
+    {
+        self.api_request_with_headers(method, path, body, query_params, options)
+            .await
+            .map(|(value, _)| value)
+    }
+
+    /// Like `api_request_with_options`, also returning the response headers
+    ///
+    /// The headers are empty in dry-run mode, where nothing is sent.
+    async fn api_request_with_headers<T, B>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+        query_params: Option<HashMap<&str, &str>>,
+        options: RequestOptions,
+    ) -> Result<(T, HeaderMap), VortexError>
+    where
+        T: serde::de::DeserializeOwned,
+        B: serde::Serialize,
+    {
+        let result = self
+            .send_request(method.clone(), path, body, query_params, options)
+            .await;
+
+        // Invalidate cached lists once a mutating request has completed
+        if is_mutating(&method) {
+            self.invalidate_caches();
+        }
+
+        result
+    }
+
+    fn invalidate_caches(&self) {
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+        if let Some(cache) = &self.invitation_cache {
+            cache.clear();
+        }
+    }
+
+    async fn send_request<T, B>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+        query_params: Option<HashMap<&str, &str>>,
+        options: RequestOptions,
+    ) -> Result<(T, HeaderMap), VortexError>
+    where
+        T: serde::de::DeserializeOwned,
+        B: serde::Serialize,
+    {
+        if self.dry_run && is_mutating(&method) {
+            let dry_run_request = DryRunRequest {
+                method: method.to_string(),
+                path: path.to_string(),
+                body: body
+                    .map(serde_json::to_value)
+                    .transpose()
+                    .map_err(|e| VortexError::SerializationError(e.to_string()))?,
+            };
+
+            match &self.dry_run_callback {
+                Some(callback) => callback(&dry_run_request),
+                None => eprintln!(
+                    "[Vortex SDK] DRY RUN: {} {} {}",
+                    dry_run_request.method,
+                    dry_run_request.path,
+                    dry_run_request
+                        .body
+                        .as_ref()
+                        .map(|b| b.to_string())
+                        .unwrap_or_default()
+                ),
+            }
+
+            return serde_json::from_str("{}")
+                .or_else(|_| serde_json::from_str("null"))
+                .map(|value| (value, HeaderMap::new()))
+                .map_err(|_| {
+                    VortexError::DryRun(format!("{} {} was not sent", method, path))
+                });
+        }
+
+        let (response, request_url) = self
+            .execute(method.clone(), path, body, query_params, options)
+            .await?;
+
+        let headers = response.headers().clone();
+        let value = self.read_response(response, &method, &request_url).await?;
+        Ok((value, headers))
+    }
+
+    /// Build and send a request, returning the raw response and the redacted request URL
+    async fn execute<B>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+        query_params: Option<HashMap<&str, &str>>,
+        options: RequestOptions,
+    ) -> Result<(reqwest::Response, String), VortexError>
+    where
+        B: serde::Serialize,
+    {
+        let (request, request_url) =
+            self.build_request(&method, path, body, query_params, options)?;
+        // Kept so a transport failure can still be matched to the id that was sent
+        let request_id = header_request_id_note(request.headers());
+
+        if let Some(breaker) = &self.circuit_breaker {
+            breaker.acquire()?;
+        }
+
+        if let Some(metrics) = &self.metrics {
+            metrics.on_request(&method, path);
+        }
+        let started = Instant::now();
+
+        let result = self.dispatch(request).await;
+
+        if let Some(metrics) = &self.metrics {
+            let status = result.as_ref().ok().map(|response| response.status().as_u16());
+            metrics.on_response(&method, path, status, started.elapsed());
+        }
+
+        if let Some(breaker) = &self.circuit_breaker {
+            let success = result
+                .as_ref()
+                .is_ok_and(|response| !response.status().is_server_error());
+            breaker.record(success);
+        }
+
+        let response = result.map_err(|e| {
+            VortexError::HttpError(format!("{} ({} {}){}", e, method, request_url, request_id))
+        })?;
+
+        Ok((response, request_url))
+    }
+
+    /// Build an authenticated request, returning it with the redacted request URL
+    fn build_request<B>(
+        &self,
+        method: &Method,
+        path: &str,
+        body: Option<&B>,
+        query_params: Option<HashMap<&str, &str>>,
+        options: RequestOptions,
+    ) -> Result<(reqwest::Request, String), VortexError>
+    where
+        B: serde::Serialize,
     {
         let url = format!("{}{}", self.base_url, path);
 
-        let mut request = match method {
-            "GET" => self.http_client.get(&url),
-            "POST" => self.http_client.post(&url),
-            "PUT" => self.http_client.put(&url),
-            "DELETE" => self.http_client.delete(&url),
-            _ => return Err(VortexError::InvalidRequest("Invalid HTTP method".to_string())),
-        };
+        let mut request = self.http_client.request(method.clone(), &url);
 
         // Add headers
+        request = match self.auth_scheme {
+            AuthScheme::ApiKeyHeader => request.header("x-api-key", self.api_key.as_str()),
+            AuthScheme::Bearer => request.bearer_auth(self.api_key.as_str()),
+        };
         request = request
             .header("Content-Type", "application/json")
-            .header("x-api-key", &self.api_key)
             .header("User-Agent", format!("vortex-rust-sdk/{}", env!("CARGO_PKG_VERSION")))
             .header("x-vortex-sdk-name", "vortex-rust-sdk")
             .header("x-vortex-sdk-version", env!("CARGO_PKG_VERSION"));
+        for (name, value) in options.headers {
+            request = request.header(name, value);
+        }
+        if let Some(key) = options.idempotency_key {
+            request = request.header("Idempotency-Key", key);
+        }
+        if self.emit_request_ids {
+            request = request.header("X-Request-Id", self.next_id());
+        }
+        if let Some(locale) = options.locale {
+            request = request.header("Accept-Language", locale);
+        }
+        if let Some(timeout) = options.timeout {
+            request = request.timeout(timeout);
+        }
 
         // Add query parameters
         if let Some(params) = query_params {
@@ -630,27 +2983,63 @@ impl VortexClient {
             request = request.json(b);
         }
 
-        let response = request
-            .send()
-            .await
+        let request = request
+            .build()
             .map_err(|e| VortexError::HttpError(e.to_string()))?;
+        let request_url = self.redact(request.url().as_str());
+        Ok((request, request_url))
+    }
+
+    /// Execute a built request on the configured transport
+    async fn dispatch(&self, request: reqwest::Request) -> Result<reqwest::Response, String> {
+        self.transport().execute(request).await
+    }
+
+    fn transport(&self) -> Transport {
+        Transport {
+            http_client: self.http_client.clone(),
+            #[cfg(feature = "middleware")]
+            middleware_client: self.middleware_client.clone(),
+        }
+    }
+
+    /// Check the response status and deserialize the body
+    async fn read_response<T>(
+        &self,
+        response: reqwest::Response,
+        method: &Method,
+        request_url: &str,
+    ) -> Result<T, VortexError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            // Only the delay-seconds form of Retry-After is understood
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse().ok())
+                .map(Duration::from_secs);
+            return Err(VortexError::RateLimited { retry_after });
+        }
 
         if !response.status().is_success() {
             let status = response.status();
+            let request_id = request_id_note(&response);
             let error_text = response
                 .text()
                 .await
                 .unwrap_or_else(|_| "Unknown error".to_string());
             return Err(VortexError::ApiError(format!(
-                "API request failed: {} - {}",
-                status, error_text
+                "API request failed: {} - {} ({} {}){}",
+                status, error_text, method, request_url, request_id
             )));
         }
 
-        let text = response
-            .text()
-            .await
-            .map_err(|e| VortexError::HttpError(e.to_string()))?;
+        let text = response.text().await.map_err(|e| {
+            VortexError::HttpError(format!("{} ({} {})", e.without_url(), method, request_url))
+        })?;
 
         // Handle empty responses
         if text.is_empty() {
@@ -662,3 +3051,359 @@ impl VortexClient {
             .map_err(|e| VortexError::SerializationError(e.to_string()))
     }
 }
+
+impl TryFrom<&str> for VortexClient {
+    type Error = VortexError;
+
+    /// Create a client after validating the API key format
+    ///
+    /// Runs the same checks as `generate_jwt`, so a malformed key fails at
+    /// startup instead of on first use.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vortex_sdk::VortexClient;
+    ///
+    /// let client = VortexClient::try_from("VRTX.AAAAAAAAAAAAAAAAAAAAAA.test_secret_key");
+    /// assert!(client.is_ok());
+    ///
+    /// let client = VortexClient::try_from("not-a-key");
+    /// assert!(client.is_err());
+    /// ```
+    fn try_from(api_key: &str) -> Result<Self, Self::Error> {
+        split_api_key(api_key)?;
+        Ok(Self::new(api_key.to_string()))
+    }
+}
+
+/// Split an API key (`VRTX.base64encodedId.key`) into its UUID string and secret
+fn split_api_key(api_key: &str) -> Result<(String, &str), VortexError> {
+    let (_, uuid, key) = parse_key_segments(api_key)?;
+
+    // HMAC accepts an empty key, which would silently sign with no secret
+    if key.is_empty() {
+        return Err(VortexError::InvalidApiKey("empty secret".to_string()));
+    }
+
+    Ok((uuid.to_string(), key))
+}
+
+/// Split an API key into its prefix, account UUID and (possibly empty) secret
+fn parse_key_segments(api_key: &str) -> Result<(&str, Uuid, &str), VortexError> {
+    // Parse API key: format is VRTX.base64encodedId.key
+    let parts: Vec<&str> = api_key.split('.').collect();
+    if parts.len() != 3 {
+        return Err(VortexError::InvalidApiKey(
+            "Invalid API key format".to_string(),
+        ));
+    }
+
+    let prefix = parts[0];
+    let encoded_id = parts[1];
+    let key = parts[2];
+
+    if prefix != "VRTX" {
+        return Err(VortexError::InvalidApiKey(
+            "Invalid API key prefix".to_string(),
+        ));
+    }
+
+    // Decode the UUID from base64url, accepting keys minted with padding or
+    // with the standard alphabet by older tools
+    let id_bytes = URL_SAFE_NO_PAD
+        .decode(encoded_id)
+        .or_else(|e| {
+            [URL_SAFE, STANDARD, STANDARD_NO_PAD]
+                .iter()
+                .find_map(|engine| engine.decode(encoded_id).ok())
+                .ok_or(e)
+        })
+        .map_err(|e| VortexError::InvalidApiKey(format!("Failed to decode ID: {}", e)))?;
+
+    if id_bytes.len() != 16 {
+        return Err(VortexError::InvalidApiKey("ID must be 16 bytes".to_string()));
+    }
+
+    let uuid = Uuid::from_slice(&id_bytes)
+        .map_err(|e| VortexError::InvalidApiKey(format!("Invalid UUID: {}", e)))?;
+
+    Ok((prefix, uuid, key))
+}
+
+/// Check that every element of a serialized `groups` claim has a non-empty `groupId` or `id`
+fn check_group_ids(groups: &serde_json::Value) -> Result<(), VortexError> {
+    for group in groups.as_array().into_iter().flatten() {
+        let has_id = ["groupId", "id"]
+            .iter()
+            .filter_map(|field| group.get(field).and_then(|v| v.as_str()))
+            .any(|id| !id.is_empty());
+        if !has_id {
+            let name = group.get("name").and_then(|v| v.as_str()).unwrap_or_default();
+            let group_type = group.get("type").and_then(|v| v.as_str()).unwrap_or_default();
+            return Err(VortexError::InvalidRequest(format!(
+                "Group `{}` ({}) has no `groupId` or `id`",
+                name, group_type
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Check that every element of a serialized array is an object with the given string fields
+fn check_shape(items: &serde_json::Value, kind: &str, fields: &[&str]) -> Result<(), VortexError> {
+    for item in items.as_array().into_iter().flatten() {
+        for field in fields {
+            if !item.get(field).is_some_and(|v| v.is_string()) {
+                return Err(VortexError::InvalidRequest(format!(
+                    "Each {} must serialize to an object with a string `{}` field",
+                    kind, field
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Decode a base64url JWT segment into a JSON object
+fn decode_jwt_segment(segment: &str) -> Result<HashMap<String, serde_json::Value>, VortexError> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(segment)
+        .map_err(|e| VortexError::InvalidRequest(format!("Malformed JWT: {}", e)))?;
+    serde_json::from_slice(&bytes).map_err(|e| VortexError::SerializationError(e.to_string()))
+}
+
+/// Whether a request with this method can change server state (anything but GET and HEAD)
+fn is_mutating(method: &Method) -> bool {
+    !matches!(*method, Method::GET | Method::HEAD)
+}
+
+/// Normalize a target value for use in list queries
+///
+/// Emails are lowercased and phone numbers reduced to E.164, matching what the
+/// target constructors send. A number that does not parse is sent as given.
+fn normalize_target_value(target_type: &str, value: &str) -> String {
+    match target_type {
+        "email" => normalize_email(value),
+        "sms" | "phone" => normalize_phone(value).unwrap_or_else(|_| value.to_string()),
+        _ => value.to_string(),
+    }
+}
+
+/// Whether the invitation was created or last modified less than `interval` before `now`
+///
+/// A timestamp in the future (clock skew) counts as just sent.
+fn sent_within(invitation: &Invitation, interval: Duration, now: SystemTime) -> bool {
+    let last_sent = invitation
+        .modified_at_time()
+        .max(invitation.created_at_time());
+    last_sent.is_some_and(|last_sent| {
+        now.duration_since(last_sent).unwrap_or(Duration::ZERO) < interval
+    })
+}
+
+/// Whether `invitation` has an acceptance matching the user's email or phone
+fn is_accepted_by(invitation: &Invitation, user: &AcceptUser) -> bool {
+    let by_email = user
+        .email
+        .as_deref()
+        .is_some_and(|email| invitation.accepted_by("email", email).is_some());
+    let by_phone = user
+        .phone
+        .as_deref()
+        .is_some_and(|phone| invitation.accepted_by("phone", phone).is_some());
+    by_email || by_phone
+}
+
+/// Derive the JWT signing key from the API key secret and key ID
+///
+/// The signing key is HMAC-SHA256(secret, kid), where `kid` is the UUID string
+/// encoded in the API key. With the `zeroize` feature the key is wiped on drop.
+pub(crate) fn derive_signing_key(
+    secret: &str,
+    kid: &str,
+) -> Result<Secret<[u8; 32]>, VortexError> {
+    let mut hmac = HmacSha256::new_from_slice(secret.as_bytes())
+        .map_err(|e| VortexError::CryptoError(format!("HMAC error: {}", e)))?;
+    hmac.update(kid.as_bytes());
+    let key: [u8; 32] = hmac.finalize().into_bytes().into();
+    Ok(into_secret(key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn test_derive_signing_key_known_vector() {
+        let key = derive_signing_key("key", "The quick brown fox jumps over the lazy dog").unwrap();
+        assert_eq!(
+            hex(key.as_slice()),
+            "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+    }
+
+    #[test]
+    fn test_derive_signing_key_depends_on_kid() {
+        let a = derive_signing_key("secret", "00000000-0000-0000-0000-000000000000").unwrap();
+        let b = derive_signing_key("secret", "00000000-0000-0000-0000-000000000001").unwrap();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_sent_within() {
+        let mut invitation: Invitation = serde_json::from_value(json!({
+            "createdAt": "1970-01-01T00:00:00Z",
+            "invitationType": "single_use",
+            "status": "delivered",
+            "expired": false
+        }))
+        .unwrap();
+        let hour = Duration::from_secs(3600);
+        let at = |secs: u64| UNIX_EPOCH + Duration::from_secs(secs);
+
+        assert!(sent_within(&invitation, hour, at(3599)));
+        assert!(!sent_within(&invitation, hour, at(3600)));
+
+        invitation.modified_at = Some("1970-01-01T02:00:00Z".to_string());
+        assert!(sent_within(&invitation, hour, at(7200 + 10)));
+        assert!(sent_within(&invitation, hour, at(0)));
+
+        invitation.created_at = String::new();
+        invitation.modified_at = None;
+        assert!(!sent_within(&invitation, hour, at(0)));
+    }
+
+    #[test]
+    fn test_request_id_header() {
+        let client = VortexClient::new("VRTX.dGVzdC1rZXk.test-secret".to_string())
+            .with_id_source(|| "req-1".to_string());
+        let build = |client: &VortexClient| {
+            let options = RequestOptions::default();
+            let (request, _) = client
+                .build_request(&Method::GET, "/api/v1/invitations", None::<&()>, None, options)
+                .unwrap();
+            request.headers().get("X-Request-Id").cloned()
+        };
+
+        assert!(build(&client).is_none());
+        let client = client.with_request_ids(true);
+        assert_eq!(build(&client).unwrap(), "req-1");
+    }
+
+    #[tokio::test]
+    async fn test_transport_error_includes_request_id() {
+        let client = VortexClient::with_base_url(
+            "VRTX.dGVzdC1rZXk.test-secret".to_string(),
+            "http://127.0.0.1:1".to_string(),
+        )
+        .with_request_ids(true)
+        .with_id_source(|| "req-1".to_string());
+
+        let result: Result<(), VortexError> = client
+            .api_request(Method::GET, "/api/v1/invitations", None::<&()>, None)
+            .await;
+
+        match result {
+            Err(VortexError::HttpError(message)) => {
+                assert!(message.ends_with("[request id: req-1]"), "{}", message);
+            }
+            other => panic!("expected HttpError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_locale_header() {
+        let client = VortexClient::new("VRTX.dGVzdC1rZXk.test-secret".to_string());
+        let build = |options: RequestOptions| {
+            let (request, _) = client
+                .build_request(&Method::POST, "/api/v1/invitations", None::<&()>, None, options)
+                .unwrap();
+            request.headers().get("Accept-Language").cloned()
+        };
+
+        assert!(build(RequestOptions::default()).is_none());
+        assert_eq!(build(RequestOptions::new().with_locale("fr-CA")).unwrap(), "fr-CA");
+    }
+
+    #[test]
+    fn test_etag_cache_lookup() {
+        let invitation: Invitation = serde_json::from_value(json!({
+            "id": "inv-1",
+            "createdAt": "1970-01-01T00:00:00Z",
+            "invitationType": "single_use",
+            "status": "delivered",
+            "expired": false
+        }))
+        .unwrap();
+        let lookup = |config: CacheConfig, allow_stale: bool| {
+            let cache = EtagCache::new(config);
+            let (generation, result) = cache.lookup("inv-1", allow_stale);
+            assert!(matches!(result, CacheLookup::Miss(None)));
+            cache.insert("inv-1", invitation.clone(), Some("\"v1\"".to_string()), generation);
+            (cache.lookup("inv-1", allow_stale).1, cache)
+        };
+        let hour = Duration::from_secs(3600);
+
+        let (result, _) = lookup(CacheConfig::new().with_max_age(hour), true);
+        assert!(matches!(result, CacheLookup::Hit(_)));
+
+        let (result, _) = lookup(CacheConfig::new(), true);
+        assert!(matches!(result, CacheLookup::Miss(Some(etag)) if etag == "\"v1\""));
+
+        let (result, cache) = lookup(CacheConfig::new().with_stale_while_revalidate(hour), true);
+        assert!(matches!(result, CacheLookup::Stale(_, Some(_))));
+        // Only one background revalidation at a time
+        assert!(matches!(cache.lookup("inv-1", true).1, CacheLookup::Hit(_)));
+        cache.end_revalidation("inv-1");
+        assert!(matches!(cache.lookup("inv-1", true).1, CacheLookup::Stale(..)));
+
+        let (result, _) = lookup(CacheConfig::new().with_stale_while_revalidate(hour), false);
+        assert!(matches!(result, CacheLookup::Miss(Some(_))));
+
+        let (_, cache) = lookup(CacheConfig::new().with_max_entries(1), true);
+        let (generation, _) = cache.lookup("inv-2", true);
+        cache.insert("inv-2", invitation.clone(), None, generation);
+        assert!(matches!(cache.lookup("inv-1", true).1, CacheLookup::Miss(None)));
+        assert!(cache.touch("inv-2", None, generation).is_some());
+    }
+
+    #[test]
+    fn test_invitation_cache_drops_writes_from_before_clear() {
+        let cache = InvitationCache::new(Duration::from_secs(3600));
+        let generation = cache.generation();
+        cache.insert("by-group".to_string(), Vec::new(), generation);
+        assert!(cache.get("by-group").is_some());
+
+        let stale_generation = cache.generation();
+        cache.clear();
+        cache.insert("by-group".to_string(), Vec::new(), stale_generation);
+        assert!(cache.get("by-group").is_none());
+    }
+
+    #[test]
+    fn test_etag_cache_drops_writes_from_before_clear() {
+        let invitation: Invitation = serde_json::from_value(json!({
+            "id": "inv-1",
+            "createdAt": "1970-01-01T00:00:00Z",
+            "invitationType": "single_use",
+            "status": "delivered",
+            "expired": false
+        }))
+        .unwrap();
+        let cache = EtagCache::new(CacheConfig::new().with_max_age(Duration::from_secs(3600)));
+        let (generation, _) = cache.lookup("inv-1", true);
+        cache.insert("inv-1", invitation.clone(), None, generation);
+
+        // A mutating request clears the cache while a revalidation is in flight
+        let (stale_generation, _) = cache.lookup("inv-1", true);
+        cache.clear();
+        assert!(cache.touch("inv-1", None, stale_generation).is_none());
+        cache.insert("inv-1", invitation.clone(), None, stale_generation);
+        assert!(matches!(cache.lookup("inv-1", true).1, CacheLookup::Miss(None)));
+    }
+}
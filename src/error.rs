@@ -1,36 +1,135 @@
-use std::fmt;
+use std::time::Duration;
 
 /// Error types for Vortex SDK operations
-#[derive(Debug)]
+///
+/// New variants may be added in minor releases. Match on [`VortexError::kind`]
+/// for a forward-compatible way to branch on the error category.
+#[derive(Debug, Clone, thiserror::Error)]
+#[non_exhaustive]
 pub enum VortexError {
     /// Invalid API key format or content
+    #[error("Invalid API key: {0}")]
     InvalidApiKey(String),
     /// Cryptographic operation failed
+    #[error("Crypto error: {0}")]
     CryptoError(String),
     /// HTTP request failed
+    #[error("HTTP error: {0}")]
     HttpError(String),
     /// API returned an error
+    #[error("API error: {0}")]
     ApiError(String),
     /// JSON serialization/deserialization failed
+    #[error("Serialization error: {0}")]
     SerializationError(String),
     /// Invalid request
+    #[error("Invalid request: {0}")]
     InvalidRequest(String),
     /// Webhook signature verification failed
-    WebhookSignatureError(String),
+    #[error("Webhook signature error: {0}")]
+    WebhookSignatureError(SignatureError),
+    /// Request was skipped because the client is in dry-run mode
+    #[error("Dry run: {0}")]
+    DryRun(String),
+    /// Request was short-circuited because the circuit breaker is open
+    #[error("Circuit open: {0}")]
+    CircuitOpen(String),
+    /// The API responded with `429 Too Many Requests`
+    #[error("Rate limited{}", retry_after_note(.retry_after))]
+    RateLimited {
+        /// How long to wait before retrying, from the `Retry-After` header
+        retry_after: Option<Duration>,
+    },
 }
 
-impl fmt::Display for VortexError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            VortexError::InvalidApiKey(msg) => write!(f, "Invalid API key: {}", msg),
-            VortexError::CryptoError(msg) => write!(f, "Crypto error: {}", msg),
-            VortexError::HttpError(msg) => write!(f, "HTTP error: {}", msg),
-            VortexError::ApiError(msg) => write!(f, "API error: {}", msg),
-            VortexError::SerializationError(msg) => write!(f, "Serialization error: {}", msg),
-            VortexError::InvalidRequest(msg) => write!(f, "Invalid request: {}", msg),
-            VortexError::WebhookSignatureError(msg) => write!(f, "Webhook signature error: {}", msg),
+fn retry_after_note(retry_after: &Option<Duration>) -> String {
+    retry_after
+        .map(|d| format!(": retry after {}s", d.as_secs()))
+        .unwrap_or_default()
+}
+
+/// Why a webhook signature could not be verified
+///
+/// `EmptySecret` and `InvalidSecret` point at misconfiguration, while
+/// `Mismatch` on a well-formed signature may indicate a forged request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SignatureErrorKind {
+    /// The signing secret is empty, or no secret would remain configured
+    EmptySecret,
+    /// The signing secret does not start with `whsec_`
+    InvalidSecret,
+    /// The signature is well-formed but matches none of the configured secrets
+    Mismatch,
+    /// The signature is not valid hex
+    Malformed,
+    /// The payload exceeds the configured size limit
+    PayloadTooLarge,
+    /// The signed timestamp is outside the accepted tolerance
+    ///
+    /// Not raised by the current signature scheme, which does not sign a timestamp.
+    TimestampOutOfTolerance,
+}
+
+/// Details of a [`VortexError::WebhookSignatureError`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{message}")]
+pub struct SignatureError {
+    pub kind: SignatureErrorKind,
+    pub message: String,
+}
+
+impl SignatureError {
+    pub fn new(kind: SignatureErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
         }
     }
 }
 
-impl std::error::Error for VortexError {}
+/// Flat classification of a [`VortexError`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    InvalidApiKey,
+    Crypto,
+    Http,
+    Api,
+    Serialization,
+    InvalidRequest,
+    WebhookSignature,
+    DryRun,
+    CircuitOpen,
+    RateLimited,
+}
+
+impl VortexError {
+    /// The category of this error
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            VortexError::InvalidApiKey(_) => ErrorKind::InvalidApiKey,
+            VortexError::CryptoError(_) => ErrorKind::Crypto,
+            VortexError::HttpError(_) => ErrorKind::Http,
+            VortexError::ApiError(_) => ErrorKind::Api,
+            VortexError::SerializationError(_) => ErrorKind::Serialization,
+            VortexError::InvalidRequest(_) => ErrorKind::InvalidRequest,
+            VortexError::WebhookSignatureError(_) => ErrorKind::WebhookSignature,
+            VortexError::DryRun(_) => ErrorKind::DryRun,
+            VortexError::CircuitOpen(_) => ErrorKind::CircuitOpen,
+            VortexError::RateLimited { .. } => ErrorKind::RateLimited,
+        }
+    }
+
+    /// The signature failure cause, for `WebhookSignatureError`
+    pub fn signature_error_kind(&self) -> Option<SignatureErrorKind> {
+        match self {
+            VortexError::WebhookSignatureError(e) => Some(e.kind),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn signature(kind: SignatureErrorKind, message: impl Into<String>) -> Self {
+        VortexError::WebhookSignatureError(SignatureError::new(kind, message))
+    }
+}
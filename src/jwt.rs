@@ -0,0 +1,151 @@
+//! Chainable JWT generation with compile-time checked options
+
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use crate::client::VortexClient;
+use crate::error::VortexError;
+use crate::types::{ExpiryClaim, JwtOptions, User};
+
+/// Builder state: the header `kid` has not been overridden
+#[derive(Debug)]
+pub struct DefaultKid;
+
+/// Builder state: the header `kid` was set with [`JwtBuilder::kid`]
+#[derive(Debug)]
+pub struct CustomKid;
+
+/// Chainable JWT generation, obtained from `VortexClient::jwt`
+///
+/// Starts from the client's `JwtOptions` and finishes with [`JwtBuilder::sign`].
+/// Options that only make sense together are enforced by the type: for
+/// example `sign_with_kid` is only available after `kid`.
+///
+/// # Example
+///
+/// ```
+/// use vortex_sdk::{User, VortexClient};
+///
+/// let client = VortexClient::new("VRTX.AAAAAAAAAAAAAAAAAAAAAA.test_secret_key".to_string());
+/// let user = User::new("user-123", "user@example.com");
+/// let jwt = client
+///     .jwt(&user)
+///     .claim("role", "admin")
+///     .kid("key-2026-10")
+///     .sign_with_kid()
+///     .sign()
+///     .unwrap();
+/// ```
+///
+/// Deriving the signing key from `kid` without setting one does not compile:
+///
+/// ```compile_fail
+/// use vortex_sdk::{User, VortexClient};
+///
+/// let client = VortexClient::new("VRTX.AAAAAAAAAAAAAAAAAAAAAA.test_secret_key".to_string());
+/// let user = User::new("user-123", "user@example.com");
+/// let jwt = client.jwt(&user).sign_with_kid().sign();
+/// ```
+#[must_use = "call `.sign()` to generate the token"]
+pub struct JwtBuilder<'a, K = DefaultKid> {
+    client: &'a VortexClient,
+    user: &'a User,
+    extra: HashMap<String, serde_json::Value>,
+    options: JwtOptions,
+    _kid: PhantomData<K>,
+}
+
+impl<'a> JwtBuilder<'a, DefaultKid> {
+    pub(crate) fn new(client: &'a VortexClient, user: &'a User, options: JwtOptions) -> Self {
+        Self {
+            client,
+            user,
+            extra: HashMap::new(),
+            options,
+            _kid: PhantomData,
+        }
+    }
+
+    /// Put `kid` in the JWT header instead of the API key's UUID
+    ///
+    /// See [`JwtOptions::with_kid`].
+    pub fn kid(self, kid: &str) -> JwtBuilder<'a, CustomKid> {
+        JwtBuilder {
+            client: self.client,
+            user: self.user,
+            extra: self.extra,
+            options: self.options.with_kid(kid),
+            _kid: PhantomData,
+        }
+    }
+}
+
+impl JwtBuilder<'_, CustomKid> {
+    /// Derive the signing key from the overridden `kid`
+    ///
+    /// See [`JwtOptions::sign_with_kid`].
+    pub fn sign_with_kid(mut self) -> Self {
+        self.options.sign_with_kid = true;
+        self
+    }
+}
+
+impl<K> JwtBuilder<'_, K> {
+    /// Add a custom payload claim; later calls override earlier ones
+    pub fn claim(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
+
+    /// Add several custom payload claims
+    pub fn claims(mut self, claims: HashMap<String, serde_json::Value>) -> Self {
+        self.extra.extend(claims);
+        self
+    }
+
+    /// Override the issued-at time (Unix seconds); `expires` is computed relative to it
+    pub fn issued_at(mut self, issued_at: u64) -> Self {
+        self.options.issued_at = Some(issued_at);
+        self
+    }
+
+    /// Include `iat` in the JWT header
+    pub fn iat_in_header(mut self, include: bool) -> Self {
+        self.options.include_iat_in_header = include;
+        self
+    }
+
+    /// Include `iat` as a payload claim
+    pub fn iat_in_payload(mut self, include: bool) -> Self {
+        self.options.include_iat_in_payload = include;
+        self
+    }
+
+    /// Also emit the user's email as a top-level `email` claim
+    pub fn promote_user_claims(mut self, promote: bool) -> Self {
+        self.options.promote_user_claims = promote;
+        self
+    }
+
+    /// Choose the claim that carries the expiry time (see [`ExpiryClaim`])
+    pub fn expiry_claim(mut self, claim: ExpiryClaim) -> Self {
+        self.options.expiry_claim = claim;
+        self
+    }
+
+    /// Set the header `typ`, e.g. `"at+jwt"`
+    pub fn typ(mut self, typ: &str) -> Self {
+        self.options.typ = typ.to_string();
+        self
+    }
+
+    /// Generate the signed token
+    ///
+    /// Equivalent to `generate_jwt_with_options` with the accumulated claims
+    /// and options, and fails under the same conditions.
+    pub fn sign(self) -> Result<String, VortexError> {
+        let extra = (!self.extra.is_empty()).then_some(self.extra);
+        self.client
+            .generate_jwt_with_options(self.user, extra, &self.options)
+    }
+}
@@ -35,14 +35,33 @@
 //! }
 //! ```
 
+mod api;
+#[cfg(feature = "axum")]
+mod axum_support;
 mod client;
 mod error;
+mod jwt;
+mod metrics;
+#[cfg(feature = "tower")]
+mod tower_support;
 mod types;
 pub mod webhook_types;
 mod webhooks;
 
+pub use api::VortexApi;
+#[cfg(feature = "axum")]
+pub use axum_support::webhook_ack;
 pub use client::VortexClient;
-pub use error::VortexError;
+pub use error::{ErrorKind, SignatureError, SignatureErrorKind, VortexError};
+pub use jwt::{CustomKid, DefaultKid, JwtBuilder};
+pub use metrics::Metrics;
+pub use reqwest::Method;
+#[cfg(feature = "tower")]
+pub use tower_support::{VortexRequest, VortexResponse, VortexService};
 pub use types::*;
+pub use uuid::Uuid;
 pub use webhook_types::*;
-pub use webhooks::VortexWebhooks;
+pub use webhooks::{
+    construct_webhook_event, verify_webhook_signature, VortexWebhooks, WebhookHeaders,
+    WEBHOOK_SIGNATURE_HEADER,
+};
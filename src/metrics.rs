@@ -0,0 +1,49 @@
+//! Observer hooks for SDK request metrics
+
+use reqwest::Method;
+use std::time::Duration;
+
+/// Observer notified around every HTTP request the client sends
+///
+/// Implement this against your metrics library (e.g. `metrics` or
+/// `prometheus`) to record request counts, error rates and latency
+/// histograms, then register it with `VortexClient::with_metrics`. `path` is
+/// the API path without the base URL or query string, e.g.
+/// `/api/v1/invitations/inv-123`.
+///
+/// Hooks run on the request path, so they should be cheap and must not block.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::atomic::{AtomicU64, Ordering};
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use vortex_sdk::{Method, Metrics, VortexClient};
+///
+/// #[derive(Default)]
+/// struct ErrorCounter(AtomicU64);
+///
+/// impl Metrics for ErrorCounter {
+///     fn on_request(&self, _method: &Method, _path: &str) {}
+///
+///     fn on_response(&self, _method: &Method, _path: &str, status: Option<u16>, _elapsed: Duration) {
+///         if status.map_or(true, |status| status >= 500) {
+///             self.0.fetch_add(1, Ordering::Relaxed);
+///         }
+///     }
+/// }
+///
+/// let client = VortexClient::new("VRTX.xxx.yyy".to_string())
+///     .with_metrics(Arc::new(ErrorCounter::default()));
+/// ```
+pub trait Metrics: Send + Sync {
+    /// Called just before a request is sent
+    fn on_request(&self, method: &Method, path: &str);
+
+    /// Called once the response headers arrive or the request fails
+    ///
+    /// `status` is `None` when no response was received (connection error,
+    /// timeout). `elapsed` is measured from just before the request was sent.
+    fn on_response(&self, method: &Method, path: &str, status: Option<u16>, elapsed: Duration);
+}
@@ -0,0 +1,168 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use tower_service::Service;
+
+use crate::client::VortexClient;
+use crate::error::VortexError;
+use crate::types::{
+    AcceptUser, CreateInvitationRequest, CreateInvitationResponse, DeleteResult, Invitation,
+    SyncInternalInvitationRequest, SyncInternalInvitationResponse,
+};
+
+/// An operation sent through [`VortexService`]
+///
+/// Each variant maps to the `VortexClient` method of the same name.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum VortexRequest {
+    GetInvitation { invitation_id: String },
+    GetInvitationsByTarget { target_type: String, target_value: String },
+    GetInvitationsByGroup { group_type: String, group_id: String },
+    RevokeInvitation { invitation_id: String },
+    AcceptInvitation { invitation_id: String, user: AcceptUser },
+    DeleteInvitationsByGroup { group_type: String, group_id: String },
+    Reinvite { invitation_id: String },
+    CreateInvitation(Box<CreateInvitationRequest>),
+    SyncInternalInvitation(SyncInternalInvitationRequest),
+}
+
+/// The typed result of a [`VortexRequest`]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum VortexResponse {
+    /// From `GetInvitation`, `AcceptInvitation` and `Reinvite`
+    Invitation(Box<Invitation>),
+    /// From `GetInvitationsByTarget` and `GetInvitationsByGroup`
+    Invitations(Vec<Invitation>),
+    /// From `RevokeInvitation`
+    Revoked,
+    /// From `DeleteInvitationsByGroup`
+    Deleted(DeleteResult),
+    /// From `CreateInvitation`
+    Created(CreateInvitationResponse),
+    /// From `SyncInternalInvitation`
+    Synced(SyncInternalInvitationResponse),
+}
+
+/// A `tower::Service` wrapping a shared [`VortexClient`]
+///
+/// Lets Vortex calls be composed with `tower` middleware such as timeouts,
+/// concurrency limits and load shedding. The service is always ready; apply
+/// backpressure with layers.
+///
+/// # Example
+///
+/// ```no_run
+/// use tower_service::Service;
+/// use vortex_sdk::{VortexClient, VortexRequest, VortexResponse, VortexService};
+///
+/// # async fn example() -> Result<(), vortex_sdk::VortexError> {
+/// let mut service = VortexService::new(VortexClient::new("VRTX.xxx.yyy".to_string()));
+/// let response = service
+///     .call(VortexRequest::GetInvitation { invitation_id: "inv-123".to_string() })
+///     .await?;
+/// if let VortexResponse::Invitation(invitation) = response {
+///     println!("{}", invitation);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct VortexService {
+    client: Arc<VortexClient>,
+}
+
+impl VortexService {
+    pub fn new(client: VortexClient) -> Self {
+        Self::from_arc(Arc::new(client))
+    }
+
+    /// Wrap a client that is also used directly elsewhere
+    pub fn from_arc(client: Arc<VortexClient>) -> Self {
+        Self { client }
+    }
+
+    /// The wrapped client
+    pub fn client(&self) -> &Arc<VortexClient> {
+        &self.client
+    }
+}
+
+impl Service<VortexRequest> for VortexService {
+    type Response = VortexResponse;
+    type Error = VortexError;
+    type Future = Pin<Box<dyn Future<Output = Result<VortexResponse, VortexError>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: VortexRequest) -> Self::Future {
+        let client = self.client.clone();
+        Box::pin(async move {
+            match request {
+                VortexRequest::GetInvitation { invitation_id } => client
+                    .get_invitation(&invitation_id)
+                    .await
+                    .map(|invitation| VortexResponse::Invitation(Box::new(invitation))),
+                VortexRequest::GetInvitationsByTarget { target_type, target_value } => client
+                    .get_invitations_by_target(&target_type, &target_value)
+                    .await
+                    .map(VortexResponse::Invitations),
+                VortexRequest::GetInvitationsByGroup { group_type, group_id } => client
+                    .get_invitations_by_group(&group_type, &group_id)
+                    .await
+                    .map(VortexResponse::Invitations),
+                VortexRequest::RevokeInvitation { invitation_id } => client
+                    .revoke_invitation(&invitation_id)
+                    .await
+                    .map(|()| VortexResponse::Revoked),
+                VortexRequest::AcceptInvitation { invitation_id, user } => client
+                    .accept_invitation(&invitation_id, user)
+                    .await
+                    .map(|invitation| VortexResponse::Invitation(Box::new(invitation))),
+                VortexRequest::DeleteInvitationsByGroup { group_type, group_id } => client
+                    .delete_invitations_by_group(&group_type, &group_id)
+                    .await
+                    .map(VortexResponse::Deleted),
+                VortexRequest::Reinvite { invitation_id } => client
+                    .reinvite(&invitation_id)
+                    .await
+                    .map(|invitation| VortexResponse::Invitation(Box::new(invitation))),
+                VortexRequest::CreateInvitation(request) => client
+                    .create_invitation(&request)
+                    .await
+                    .map(VortexResponse::Created),
+                VortexRequest::SyncInternalInvitation(request) => client
+                    .sync_internal_invitation(&request)
+                    .await
+                    .map(VortexResponse::Synced),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_service_dispatches_to_client() {
+        let client = VortexClient::with_base_url(
+            "VRTX.dGVzdC1rZXk.test-secret".to_string(),
+            "http://127.0.0.1:1".to_string(),
+        );
+        let mut service = VortexService::new(client);
+
+        let future = service.call(VortexRequest::GetInvitation {
+            invitation_id: "inv-123".to_string(),
+        });
+        match tokio::spawn(future).await.unwrap() {
+            Err(VortexError::HttpError(msg)) => assert!(msg.contains("/api/v1/invitations/inv-123")),
+            other => panic!("Expected HttpError, got {:?}", other),
+        }
+    }
+}
@@ -1,5 +1,10 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::error::VortexError;
 
 // ============================================================================
 // Enums for type-safe API values
@@ -10,6 +15,7 @@ use std::collections::HashMap;
 #[serde(rename_all = "snake_case")]
 pub enum InvitationTargetType {
     Email,
+    #[serde(alias = "sms")]
     Phone,
     Share,
     Internal,
@@ -34,7 +40,7 @@ pub enum InvitationType {
 }
 
 /// Current status of an invitation
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum InvitationStatus {
     Queued,
@@ -47,6 +53,34 @@ pub enum InvitationStatus {
     AcceptedElsewhere,
 }
 
+impl fmt::Display for InvitationTargetType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            InvitationTargetType::Email => "email",
+            InvitationTargetType::Phone => "phone",
+            InvitationTargetType::Share => "share",
+            InvitationTargetType::Internal => "internal",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+impl fmt::Display for InvitationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            InvitationStatus::Queued => "queued",
+            InvitationStatus::Sending => "sending",
+            InvitationStatus::Sent => "sent",
+            InvitationStatus::Delivered => "delivered",
+            InvitationStatus::Accepted => "accepted",
+            InvitationStatus::Shared => "shared",
+            InvitationStatus::Unfurled => "unfurled",
+            InvitationStatus::AcceptedElsewhere => "accepted_elsewhere",
+        };
+        write!(f, "{}", value)
+    }
+}
+
 /// Delivery type for invitations
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -57,6 +91,32 @@ pub enum DeliveryType {
     Internal,
 }
 
+/// How the API key is sent with API requests
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthScheme {
+    /// `x-api-key: <key>` (default)
+    #[default]
+    ApiKeyHeader,
+    /// `Authorization: Bearer <key>`
+    Bearer,
+}
+
+/// Which HTTP version the client uses to talk to the Vortex API
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HttpVersion {
+    /// HTTP/1.1, or HTTP/2 when negotiated via TLS ALPN (default)
+    ///
+    /// ALPN negotiation requires the `http2-alpn` Cargo feature.
+    #[default]
+    Negotiate,
+    /// HTTP/1.1 only
+    Http1Only,
+    /// HTTP/2 without negotiation, multiplexing requests over fewer connections
+    Http2PriorKnowledge,
+}
+
 // ============================================================================
 // Core types
 // ============================================================================
@@ -112,7 +172,251 @@ impl User {
     }
 }
 
+/// Normalize a phone number to E.164 format (e.g., "+15555550100")
+///
+/// Spaces, dashes, dots and parentheses are stripped. The number must start
+/// with `+` followed by a country code and contain 8 to 15 digits in total.
+///
+/// # Example
+///
+/// ```
+/// use vortex_sdk::normalize_phone;
+///
+/// assert_eq!(normalize_phone("+1 (555) 555-0100").unwrap(), "+15555550100");
+/// assert!(normalize_phone("555-0100").is_err());
+/// ```
+pub fn normalize_phone(value: &str) -> Result<String, VortexError> {
+    let invalid = || VortexError::InvalidRequest(format!("Invalid phone number: {}", value));
+
+    let compact: String = value
+        .trim()
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')'))
+        .collect();
+
+    let digits = compact.strip_prefix('+').ok_or_else(invalid)?;
+    if !(8..=15).contains(&digits.len())
+        || !digits.chars().all(|c| c.is_ascii_digit())
+        || digits.starts_with('0')
+    {
+        return Err(invalid());
+    }
+
+    Ok(compact)
+}
+
+/// Normalize an email address for storage and lookups: trimmed and lowercased
+///
+/// # Example
+///
+/// ```
+/// use vortex_sdk::normalize_email;
+///
+/// assert_eq!(normalize_email(" User@Example.com "), "user@example.com");
+/// ```
+pub fn normalize_email(value: &str) -> String {
+    value.trim().to_lowercase()
+}
+
+/// Known identifier types, with an escape hatch for custom values
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdentifierType {
+    Email,
+    Sms,
+    Custom(String),
+}
+
+impl IdentifierType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            IdentifierType::Email => "email",
+            IdentifierType::Sms => "sms",
+            IdentifierType::Custom(value) => value,
+        }
+    }
+}
+
+impl From<&str> for IdentifierType {
+    fn from(value: &str) -> Self {
+        match value {
+            "email" => IdentifierType::Email,
+            "sms" => IdentifierType::Sms,
+            other => IdentifierType::Custom(other.to_string()),
+        }
+    }
+}
+
+/// Which claim carries a generated JWT's expiry time (Unix seconds)
+///
+/// The Vortex widget and React provider read `expires`. Stock RFC 7519
+/// libraries (e.g. `jsonwebtoken`, `jose`, PyJWT) read only `exp`. Use
+/// `Both` while verifiers of both kinds accept the same tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExpiryClaim {
+    /// `expires` (default)
+    #[default]
+    Expires,
+    /// `exp`, as defined by RFC 7519
+    Exp,
+    /// Both `expires` and `exp`, with the same value
+    Both,
+}
+
+/// Options controlling how JWTs are generated
+///
+/// By default `iat` is placed in the JWT header, matching the other Vortex SDKs.
+#[derive(Debug, Clone)]
+pub struct JwtOptions {
+    /// Include `iat` in the JWT header (default: true)
+    pub include_iat_in_header: bool,
+    /// Include `iat` as a payload claim (default: false)
+    pub include_iat_in_payload: bool,
+    /// Override the issued-at time (Unix seconds); defaults to now
+    pub issued_at: Option<u64>,
+    /// Also emit the user's email as a top-level `email` claim (default: false)
+    pub promote_user_claims: bool,
+    /// Header `kid` to use instead of the API key's UUID
+    pub kid: Option<String>,
+    /// Derive the signing key from `kid` rather than the API key's UUID (default: false)
+    pub sign_with_kid: bool,
+    /// Header `typ` (default: `"JWT"`)
+    pub typ: String,
+    /// Accept `groups` claims entries without an `id` or `groupId` (default: false)
+    pub allow_groups_without_id: bool,
+    /// Claim carrying the expiry time (default: `expires`)
+    pub expiry_claim: ExpiryClaim,
+}
+
+impl Default for JwtOptions {
+    fn default() -> Self {
+        Self {
+            include_iat_in_header: true,
+            include_iat_in_payload: false,
+            issued_at: None,
+            promote_user_claims: false,
+            kid: None,
+            sign_with_kid: false,
+            typ: "JWT".to_string(),
+            allow_groups_without_id: false,
+            expiry_claim: ExpiryClaim::default(),
+        }
+    }
+}
+
+impl JwtOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn include_iat_in_header(mut self, include: bool) -> Self {
+        self.include_iat_in_header = include;
+        self
+    }
+
+    pub fn include_iat_in_payload(mut self, include: bool) -> Self {
+        self.include_iat_in_payload = include;
+        self
+    }
+
+    /// Override the issued-at time; `expires` is computed relative to it
+    pub fn with_issued_at(mut self, issued_at: u64) -> Self {
+        self.issued_at = Some(issued_at);
+        self
+    }
+
+    /// Promote the user's id and email to top-level `userId` and `email` claims
+    ///
+    /// `userId` and `userEmail` are always present; with this flag the payload
+    /// additionally carries `email`, for providers that read it from the root:
+    ///
+    /// ```json
+    /// { "userId": "user-123", "userEmail": "user@example.com", "email": "user@example.com", "expires": 1767229200 }
+    /// ```
+    ///
+    /// Both claims are taken from the `User`; entries in `extra` still override them.
+    pub fn promote_user_claims(mut self, promote: bool) -> Self {
+        self.promote_user_claims = promote;
+        self
+    }
+
+    /// Put `kid` in the JWT header instead of the API key's UUID
+    ///
+    /// For verifiers that select keys by a version-tagged `kid`. By default
+    /// the token is still signed with the key derived from the API key's UUID,
+    /// so only the header changes; combine with `sign_with_kid(true)` to derive
+    /// the signing key from `kid` instead. Either way, `VortexClient::decode_jwt`
+    /// only accepts tokens whose `kid` is the API key's UUID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vortex_sdk::{JwtOptions, VortexClient, User};
+    ///
+    /// let client = VortexClient::new("VRTX.AAAAAAAAAAAAAAAAAAAAAA.test_secret_key".to_string());
+    /// let user = User::new("user-123", "user@example.com");
+    /// let options = JwtOptions::new().with_kid("key-2026-10");
+    /// let jwt = client.generate_jwt_with_options(&user, None, &options).unwrap();
+    /// ```
+    pub fn with_kid(mut self, kid: &str) -> Self {
+        self.kid = Some(kid.to_string());
+        self
+    }
+
+    /// Derive the signing key from the overridden `kid` (see [`JwtOptions::with_kid`])
+    pub fn sign_with_kid(mut self, sign_with_kid: bool) -> Self {
+        self.sign_with_kid = sign_with_kid;
+        self
+    }
+
+    /// Choose the claim that carries the expiry time (see [`ExpiryClaim`])
+    pub fn with_expiry_claim(mut self, claim: ExpiryClaim) -> Self {
+        self.expiry_claim = claim;
+        self
+    }
+
+    /// Skip the check that every entry of the `groups` claim has an id
+    ///
+    /// By default a group with neither `id` nor `groupId` is rejected with
+    /// `VortexError::InvalidRequest`, because the widget cannot match the
+    /// user to it and the membership silently has no effect.
+    pub fn allow_groups_without_id(mut self, allow: bool) -> Self {
+        self.allow_groups_without_id = allow;
+        self
+    }
+
+    /// Set the header `typ`, e.g. `"at+jwt"` for OAuth 2.0 access tokens (RFC 9068)
+    ///
+    /// The header is part of the signed input, so the signature covers it.
+    pub fn with_typ(mut self, typ: &str) -> Self {
+        self.typ = typ.to_string();
+        self
+    }
+}
+
+/// Components of an API key, as returned by `VortexClient::parse_api_key`
+///
+/// The secret itself is never exposed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiKeyParts {
+    /// The key prefix, always `VRTX`
+    pub prefix: String,
+    /// The account UUID encoded in the key
+    pub account_uuid: uuid::Uuid,
+    /// True if the key has a non-empty secret segment
+    pub secret_present: bool,
+}
+
+/// A verified JWT split into its decoded header and payload claims
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecodedJwt {
+    pub header: HashMap<String, serde_json::Value>,
+    pub payload: HashMap<String, serde_json::Value>,
+}
+
 /// Identifier for a user (email, sms, etc.)
+///
+/// Prefer the typed constructors [`Identifier::email`] and [`Identifier::sms`],
+/// which can't be mistyped.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Identifier {
     #[serde(rename = "type")]
@@ -121,17 +425,81 @@ pub struct Identifier {
 }
 
 impl Identifier {
+    /// Create an identifier from a raw type string
+    ///
+    /// Unknown types are passed through as-is; use [`Identifier::validate`] to catch typos.
     pub fn new(identifier_type: &str, value: &str) -> Self {
         Self {
             identifier_type: identifier_type.to_string(),
             value: value.to_string(),
         }
     }
+
+    pub fn with_type(identifier_type: IdentifierType, value: &str) -> Self {
+        Self::new(identifier_type.as_str(), value)
+    }
+
+    pub fn email(value: &str) -> Self {
+        Self::with_type(IdentifierType::Email, value)
+    }
+
+    /// Create an sms identifier, normalizing the value to E.164
+    ///
+    /// Returns `VortexError::InvalidRequest` if the value is not a valid phone number.
+    pub fn sms(value: &str) -> Result<Self, VortexError> {
+        Ok(Self::with_type(IdentifierType::Sms, &normalize_phone(value)?))
+    }
+
+    /// The parsed identifier type
+    pub fn kind(&self) -> IdentifierType {
+        IdentifierType::from(self.identifier_type.as_str())
+    }
+
+    /// Check that the value looks like an email or phone number for the known types
+    ///
+    /// Custom types only require a non-empty type and value.
+    pub fn validate(&self) -> Result<(), VortexError> {
+        match self.kind() {
+            IdentifierType::Email => {
+                let valid = match self.value.split_once('@') {
+                    Some((local, domain)) => {
+                        !local.is_empty() && domain.contains('.') && !domain.contains('@')
+                    }
+                    None => false,
+                };
+                if !valid {
+                    return Err(VortexError::InvalidRequest(format!(
+                        "Invalid email identifier: {}",
+                        self.value
+                    )));
+                }
+            }
+            IdentifierType::Sms => {
+                if normalize_phone(&self.value).ok().as_deref() != Some(self.value.as_str()) {
+                    return Err(VortexError::InvalidRequest(format!(
+                        "Invalid sms identifier: {}",
+                        self.value
+                    )));
+                }
+            }
+            IdentifierType::Custom(identifier_type) => {
+                if identifier_type.is_empty() || self.value.is_empty() {
+                    return Err(VortexError::InvalidRequest(
+                        "Identifier type and value must not be empty".to_string(),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Group information for JWT generation (input)
 /// Supports both 'id' (legacy) and 'groupId' (preferred) for backward compatibility
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Struct literals should end with `..Default::default()` so they keep
+/// compiling as optional fields are added.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Group {
     #[serde(rename = "type")]
@@ -141,6 +509,9 @@ pub struct Group {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub group_id: Option<String>,
     pub name: String,
+    /// Group-scoped context for the widget, e.g. the user's role in the group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<HashMap<String, serde_json::Value>>,
 }
 
 impl Group {
@@ -150,6 +521,7 @@ impl Group {
             id: None,
             group_id: None,
             name: name.to_string(),
+            attributes: None,
         }
     }
 
@@ -162,6 +534,30 @@ impl Group {
         self.group_id = Some(group_id.to_string());
         self
     }
+
+    pub fn with_attributes(mut self, attributes: HashMap<String, serde_json::Value>) -> Self {
+        self.attributes = Some(attributes);
+        self
+    }
+
+    /// Set a single attribute, keeping any others already set
+    pub fn with_attribute(mut self, key: &str, value: impl Into<serde_json::Value>) -> Self {
+        self.attributes
+            .get_or_insert_with(HashMap::new)
+            .insert(key.to_string(), value.into());
+        self
+    }
+
+    /// The id the widget matches the group by: `group_id`, falling back to the legacy `id`
+    ///
+    /// Empty strings are treated as missing.
+    pub fn resolved_id(&self) -> Option<&str> {
+        [&self.group_id, &self.id]
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .find(|id| !id.is_empty())
+    }
 }
 
 /// Invitation group from API responses
@@ -209,14 +605,27 @@ impl InvitationTarget {
         }
     }
 
+    /// Create an email target; the address is normalized with [`normalize_email`]
+    ///
+    /// `CreateInvitationTarget::email` and the target lookups
+    /// (`get_invitations_by_target`, `revoke_invitations_by_target`,
+    /// `get_groups_by_target`) normalize the same way, so an invitation
+    /// created for `User@Example.com` is found by any casing of the address.
     pub fn email(value: &str) -> Self {
-        Self::new(InvitationTargetType::Email, value)
+        Self::new(InvitationTargetType::Email, &normalize_email(value))
     }
 
     pub fn phone(value: &str) -> Self {
         Self::new(InvitationTargetType::Phone, value)
     }
 
+    /// Create a phone target, normalizing the value to E.164
+    ///
+    /// Returns `VortexError::InvalidRequest` if the value is not a valid phone number.
+    pub fn sms(value: &str) -> Result<Self, VortexError> {
+        Ok(Self::phone(&normalize_phone(value)?))
+    }
+
     pub fn with_name(mut self, name: &str) -> Self {
         self.name = Some(name.to_string());
         self
@@ -226,6 +635,45 @@ impl InvitationTarget {
         self.avatar_url = Some(avatar_url.to_string());
         self
     }
+
+    /// A copy of this target with its value normalized
+    ///
+    /// Emails are trimmed and lowercased; phone numbers are converted to E.164
+    /// when valid and left unchanged otherwise. Other target types are unchanged.
+    pub fn normalized(&self) -> Self {
+        let value = match self.target_type {
+            InvitationTargetType::Email => normalize_email(&self.value),
+            InvitationTargetType::Phone => {
+                normalize_phone(&self.value).unwrap_or_else(|_| self.value.clone())
+            }
+            _ => self.value.clone(),
+        };
+        Self {
+            value,
+            ..self.clone()
+        }
+    }
+
+    /// Check whether this target matches the given type and value
+    ///
+    /// Emails are compared case-insensitively and phone numbers after E.164
+    /// normalization. `"sms"` is accepted as an alias for `"phone"`.
+    pub fn matches(&self, target_type: &str, target_value: &str) -> bool {
+        match (&self.target_type, target_type) {
+            (InvitationTargetType::Email, "email") => {
+                self.value.trim().eq_ignore_ascii_case(target_value.trim())
+            }
+            (InvitationTargetType::Phone, "phone" | "sms") => {
+                match (normalize_phone(&self.value), normalize_phone(target_value)) {
+                    (Ok(a), Ok(b)) => a == b,
+                    _ => self.value == target_value,
+                }
+            }
+            (InvitationTargetType::Share, "share")
+            | (InvitationTargetType::Internal, "internal") => self.value == target_value,
+            _ => false,
+        }
+    }
 }
 
 /// User data for accepting invitations (preferred format)
@@ -344,12 +792,528 @@ pub struct Invitation {
     pub creator_avatar_url: Option<String>,
 }
 
+impl Invitation {
+    /// Parse an invitation from a JSON body fetched outside the client
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vortex_sdk::Invitation;
+    ///
+    /// let result = Invitation::from_json("{}");
+    /// assert!(result.is_err());
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, VortexError> {
+        serde_json::from_str(json).map_err(|e| {
+            VortexError::SerializationError(format!("Failed to parse invitation: {}", e))
+        })
+    }
+
+    /// Find the acceptance made by the given target, if any
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use vortex_sdk::Invitation;
+    /// # fn example(invitation: &Invitation) {
+    /// if let Some(acceptance) = invitation.accepted_by("email", "User@Example.com") {
+    ///     println!("Accepted at {:?}", acceptance.accepted_at);
+    /// }
+    /// # }
+    /// ```
+    pub fn accepted_by(
+        &self,
+        target_type: &str,
+        target_value: &str,
+    ) -> Option<&InvitationAcceptance> {
+        self.accepts.iter().find(|acceptance| {
+            acceptance
+                .target
+                .as_ref()
+                .is_some_and(|target| target.matches(target_type, target_value))
+        })
+    }
+
+    /// True if the invitation is still live and has not been accepted
+    pub fn is_pending(&self) -> bool {
+        !self.deactivated
+            && !matches!(
+                self.status,
+                InvitationStatus::Accepted | InvitationStatus::AcceptedElsewhere
+            )
+    }
+
+    /// `created_at` parsed as an RFC 3339 timestamp, or `None` if it is missing or malformed
+    pub fn created_at_time(&self) -> Option<SystemTime> {
+        parse_timestamp(&self.created_at)
+    }
+
+    /// `expires` parsed as an RFC 3339 timestamp, or `None` if it is missing or malformed
+    pub fn expires_time(&self) -> Option<SystemTime> {
+        self.expires.as_deref().and_then(parse_timestamp)
+    }
+
+    /// True if the API flagged the invitation as expired or its `expires` time has passed
+    ///
+    /// The `expired` flag is computed when the invitation is fetched, so the
+    /// `expires` timestamp is also checked against the current time to catch
+    /// invitations that lapsed after being fetched or cached.
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(SystemTime::now())
+    }
+
+    /// Like [`Invitation::is_expired`], evaluated at `now`
+    pub fn is_expired_at(&self, now: SystemTime) -> bool {
+        self.expired || self.expires_time().is_some_and(|expires| expires <= now)
+    }
+
+    /// `modified_at` parsed as an RFC 3339 timestamp, or `None` if it is missing or malformed
+    pub fn modified_at_time(&self) -> Option<SystemTime> {
+        self.modified_at.as_deref().and_then(parse_timestamp)
+    }
+
+    /// Compare by creation time, honoring UTC offsets
+    ///
+    /// Invitations with a missing or malformed `created_at` sort first.
+    pub fn cmp_by_created_at(&self, other: &Self) -> Ordering {
+        self.created_at_time().cmp(&other.created_at_time())
+    }
+
+    /// Values of the email targets
+    pub fn target_emails(&self) -> Vec<&str> {
+        self.target_values(InvitationTargetType::Email)
+    }
+
+    /// Values of the phone (sms) targets
+    pub fn target_phones(&self) -> Vec<&str> {
+        self.target_values(InvitationTargetType::Phone)
+    }
+
+    fn target_values(&self, target_type: InvitationTargetType) -> Vec<&str> {
+        self.target
+            .iter()
+            .filter(|target| target.target_type == target_type)
+            .map(|target| target.value.as_str())
+            .collect()
+    }
+}
+
+/// Sort invitations by creation time, oldest first
+///
+/// Each `created_at` is parsed once. Invitations with a missing or malformed
+/// timestamp sort first; the sort is stable.
+///
+/// # Example
+///
+/// ```
+/// use vortex_sdk::{sort_invitations_by_created, Invitation};
+///
+/// let invitation = |id: &str, created_at: &str| {
+///     Invitation::from_json(&format!(
+///         r#"{{"id":"{id}","createdAt":"{created_at}","invitationType":"single_use","status":"delivered","expired":false}}"#
+///     ))
+///     .unwrap()
+/// };
+/// let mut invitations = vec![
+///     invitation("newer", "2026-02-25T12:00:00Z"),
+///     invitation("older", "2026-01-01T00:00:00Z"),
+/// ];
+///
+/// sort_invitations_by_created(&mut invitations);
+/// assert_eq!(invitations[0].id, "older");
+/// assert_eq!(invitations[1].id, "newer");
+/// ```
+pub fn sort_invitations_by_created(invitations: &mut [Invitation]) {
+    invitations.sort_by_cached_key(Invitation::created_at_time);
+}
+
+/// Sort invitations by creation time, newest first
+///
+/// Invitations with a missing or malformed timestamp sort last.
+///
+/// # Example
+///
+/// ```
+/// use vortex_sdk::{sort_invitations_by_created_desc, Invitation};
+///
+/// let invitation = |id: &str, created_at: &str| {
+///     Invitation::from_json(&format!(
+///         r#"{{"id":"{id}","createdAt":"{created_at}","invitationType":"single_use","status":"delivered","expired":false}}"#
+///     ))
+///     .unwrap()
+/// };
+/// let mut invitations = vec![
+///     invitation("older", "2026-01-01T00:00:00Z"),
+///     invitation("newer", "2026-02-25T12:00:00Z"),
+/// ];
+///
+/// sort_invitations_by_created_desc(&mut invitations);
+/// assert_eq!(invitations[0].id, "newer");
+/// assert_eq!(invitations[1].id, "older");
+/// ```
+pub fn sort_invitations_by_created_desc(invitations: &mut [Invitation]) {
+    invitations.sort_by_cached_key(|invitation| std::cmp::Reverse(invitation.created_at_time()));
+}
+
+/// Parse an RFC 3339 timestamp such as `2026-02-25T12:00:00.123Z` or
+/// `2026-02-25T14:00:00+02:00`
+pub(crate) fn parse_timestamp(value: &str) -> Option<SystemTime> {
+    let value = value.trim();
+    let bytes = value.as_bytes();
+    if bytes.len() < 20 || !matches!(bytes[10], b'T' | b't' | b' ') {
+        return None;
+    }
+    let number = |range: std::ops::Range<usize>| -> Option<i64> {
+        let digits = value.get(range)?;
+        if !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        digits.parse().ok()
+    };
+    if bytes[4] != b'-' || bytes[7] != b'-' || bytes[13] != b':' || bytes[16] != b':' {
+        return None;
+    }
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+
+    // Optional fractional seconds
+    let mut rest = &value[19..];
+    let mut nanos = 0u32;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let len = fraction.bytes().take_while(|b| b.is_ascii_digit()).count();
+        if len == 0 {
+            return None;
+        }
+        let digits = &fraction[..len.min(9)];
+        nanos = digits.parse::<u32>().ok()? * 10u32.pow(9 - digits.len() as u32);
+        rest = &fraction[len..];
+    }
+
+    let offset_secs = match rest {
+        "Z" | "z" => 0,
+        _ => {
+            let sign = match rest.as_bytes().first()? {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            let offset = rest.get(1..)?;
+            if offset.len() != 5 || offset.as_bytes()[2] != b':' {
+                return None;
+            }
+            let hours: i64 = offset[..2].parse().ok()?;
+            let minutes: i64 = offset[3..].parse().ok()?;
+            sign * (hours * 3600 + minutes * 60)
+        }
+    };
+
+    let secs = days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second
+        - offset_secs;
+    let since_epoch = Duration::new(secs.unsigned_abs(), 0);
+    let time = if secs >= 0 {
+        UNIX_EPOCH.checked_add(since_epoch)?
+    } else {
+        UNIX_EPOCH.checked_sub(since_epoch)?
+    };
+    time.checked_add(Duration::from_nanos(nanos.into()))
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Concise one-line summary for logging: id, status and targets
+impl fmt::Display for Invitation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invitation {} [{}]", self.id, self.status)?;
+        for (i, target) in self.target.iter().enumerate() {
+            let sep = if i == 0 { " -> " } else { ", " };
+            write!(f, "{}{}:{}", sep, target.target_type, target.value)?;
+        }
+        Ok(())
+    }
+}
+
+/// A trimmed view of an [`Invitation`] for exposing to frontends
+///
+/// # Example
+///
+/// ```no_run
+/// # use vortex_sdk::{Invitation, InvitationSummary};
+/// # fn example(invitations: &[Invitation]) {
+/// let summaries: Vec<InvitationSummary> = invitations.iter().map(InvitationSummary::from).collect();
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvitationSummary {
+    pub id: String,
+    pub status: InvitationStatus,
+    pub targets: Vec<InvitationSummaryTarget>,
+    pub created_at: String,
+    /// Number of acceptances recorded on the invitation
+    pub accept_count: usize,
+}
+
+/// Target type and value of an [`InvitationSummary`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InvitationSummaryTarget {
+    #[serde(rename = "type")]
+    pub target_type: InvitationTargetType,
+    pub value: String,
+}
+
+impl From<&Invitation> for InvitationSummary {
+    fn from(invitation: &Invitation) -> Self {
+        Self {
+            id: invitation.id.clone(),
+            status: invitation.status.clone(),
+            targets: invitation
+                .target
+                .iter()
+                .map(|target| InvitationSummaryTarget {
+                    target_type: target.target_type.clone(),
+                    value: target.value.clone(),
+                })
+                .collect(),
+            created_at: invitation.created_at.clone(),
+            accept_count: invitation.accepts.len(),
+        }
+    }
+}
+
+impl From<Invitation> for InvitationSummary {
+    fn from(invitation: Invitation) -> Self {
+        Self::from(&invitation)
+    }
+}
+
+/// Invitations capped at a maximum count
+#[derive(Debug, Clone)]
+pub struct LimitedInvitations {
+    pub invitations: Vec<Invitation>,
+    /// True if more invitations existed than were returned
+    pub truncated: bool,
+}
+
+/// Outcome of a bulk delete such as `delete_invitations_by_group`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeleteResult {
+    /// Number of invitations deleted, if the API reported it
+    pub deleted: Option<u32>,
+    /// IDs of invitations that could not be deleted
+    pub failed: Vec<String>,
+}
+
+/// Safety checks for `delete_invitations_by_group_with_options`
+#[derive(Debug, Clone, Default)]
+pub struct DeleteOptions {
+    /// Refuse to delete unless the group holds exactly this many invitations
+    pub confirm_count: Option<usize>,
+}
+
+impl DeleteOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_confirm_count(mut self, confirm_count: usize) -> Self {
+        self.confirm_count = Some(confirm_count);
+        self
+    }
+}
+
+/// Outcome of a bulk operation that calls the API once per invitation
+#[derive(Debug, Default)]
+pub struct BulkResult {
+    /// Invitations the operation succeeded for, as returned by the API
+    pub succeeded: Vec<Invitation>,
+    /// IDs of invitations the operation failed for, with the error for each
+    pub failed: Vec<(String, VortexError)>,
+}
+
+/// Outcome of accepting an invitation for a user
+#[derive(Debug, Clone)]
+pub struct AcceptResult {
+    pub invitation: Invitation,
+    /// False if the user had already accepted the invitation before this request
+    pub newly_accepted: bool,
+}
+
+/// Result of a conditional (`If-None-Match`) request
+#[derive(Debug, Clone)]
+pub enum ConditionalResponse<T> {
+    /// The resource changed (or no ETag was sent); contains the new value
+    Modified { value: T, etag: Option<String> },
+    /// The resource is unchanged since the given ETag (`304 Not Modified`)
+    NotModified { etag: Option<String> },
+}
+
+/// Filters for invitation list queries
+#[derive(Debug, Clone, Default)]
+pub struct ListInvitationsOptions {
+    /// Only return invitations modified at or after this ISO 8601 timestamp
+    pub modified_since: Option<String>,
+    /// Only return invitations in this environment (the `environment_id` carried by webhook events)
+    pub environment_id: Option<String>,
+    /// Overrides the client timeout for this call
+    pub timeout: Option<Duration>,
+}
+
+impl ListInvitationsOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_modified_since(mut self, modified_since: &str) -> Self {
+        self.modified_since = Some(modified_since.to_string());
+        self
+    }
+
+    pub fn with_environment_id(mut self, environment_id: &str) -> Self {
+        self.environment_id = Some(environment_id.to_string());
+        self
+    }
+
+    /// Override the client timeout for this call
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Per-call request settings implied by these options
+    pub(crate) fn request_options(&self) -> RequestOptions {
+        RequestOptions {
+            timeout: self.timeout,
+            ..RequestOptions::default()
+        }
+    }
+
+    /// Add the set filters to a query parameter map
+    pub(crate) fn apply<'a>(&'a self, params: &mut HashMap<&'a str, &'a str>) {
+        if let Some(ref modified_since) = self.modified_since {
+            params.insert("modifiedSince", modified_since);
+        }
+        if let Some(ref environment_id) = self.environment_id {
+            params.insert("environmentId", environment_id);
+        }
+    }
+}
+
+/// Per-call settings layered on top of the client defaults
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use vortex_sdk::RequestOptions;
+///
+/// let options = RequestOptions::new()
+///     .with_timeout(Duration::from_millis(500))
+///     .with_idempotency_key("create-inv-42");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    /// Overrides the client timeout for this call
+    pub timeout: Option<Duration>,
+    /// Sent as the `Idempotency-Key` header
+    pub idempotency_key: Option<String>,
+    /// Sent as the `Accept-Language` header, e.g. `"fr-CA"`
+    ///
+    /// Selects the language of invitation emails and SMS delivered as a
+    /// result of the call.
+    pub locale: Option<String>,
+    /// Extra headers set by the SDK itself
+    pub(crate) headers: Vec<(&'static str, String)>,
+}
+
+impl RequestOptions {
+    /// Options that leave every client default in place
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the client timeout for this call
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Send an `Idempotency-Key` header so the API can deduplicate retries
+    pub fn with_idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
+    /// Send an `Accept-Language` header selecting the delivery language
+    pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+}
+
 /// Response containing multiple invitations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InvitationsResponse {
     pub invitations: Option<Vec<Invitation>>,
 }
 
+impl InvitationsResponse {
+    /// Parse an invitation list response from a JSON body fetched outside the client
+    pub fn from_json(json: &str) -> Result<Self, VortexError> {
+        serde_json::from_str(json).map_err(|e| {
+            VortexError::SerializationError(format!("Failed to parse invitations: {}", e))
+        })
+    }
+
+    /// The invitations, treating a missing list as empty
+    pub fn into_vec(self) -> Vec<Invitation> {
+        self.invitations.unwrap_or_default()
+    }
+}
+
+impl IntoIterator for InvitationsResponse {
+    type Item = Invitation;
+    type IntoIter = std::vec::IntoIter<Invitation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_vec().into_iter()
+    }
+}
+
+/// Response that may be either a single object or an array of objects
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+impl<T> OneOrMany<T> {
+    pub(crate) fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::Many(items) => items,
+            OneOrMany::One(item) => vec![item],
+        }
+    }
+}
+
 /// Accept invitation parameter - supports both new User format and legacy Target format
 #[derive(Debug, Clone)]
 pub enum AcceptInvitationParam {
@@ -407,16 +1371,20 @@ impl CreateInvitationTarget {
         }
     }
 
+    /// Create an email target; the address is normalized with [`normalize_email`]
     pub fn email(value: &str) -> Self {
-        Self::new(CreateInvitationTargetType::Email, value)
+        Self::new(CreateInvitationTargetType::Email, &normalize_email(value))
     }
 
-    pub fn phone(value: &str) -> Self {
-        Self::new(CreateInvitationTargetType::Phone, value)
+    /// Create a phone target, normalizing the value to E.164 with [`normalize_phone`]
+    ///
+    /// Returns `VortexError::InvalidRequest` if the value is not a valid phone number.
+    pub fn phone(value: &str) -> Result<Self, VortexError> {
+        Ok(Self::new(CreateInvitationTargetType::Phone, &normalize_phone(value)?))
     }
 
     /// Alias for phone (backward compatibility)
-    pub fn sms(value: &str) -> Self {
+    pub fn sms(value: &str) -> Result<Self, VortexError> {
         Self::phone(value)
     }
 
@@ -653,6 +1621,16 @@ pub struct CreateInvitationResponse {
     pub created_at: String,
 }
 
+/// Outcome of `VortexClient::create_invitation_idempotent`
+#[derive(Debug, Clone)]
+pub struct CreateResult {
+    pub invitation: CreateInvitationResponse,
+    /// False when the API returned the invitation created by an earlier
+    /// request with the same idempotency key, as signalled by the
+    /// `Idempotent-Replayed: true` response header
+    pub created: bool,
+}
+
 // --- Types for syncing internal invitation actions ---
 
 /// Request body for syncing an internal invitation action
@@ -743,3 +1721,203 @@ impl ConfigureAutojoinRequest {
         self
     }
 }
+
+// --- Types for dry-run mode ---
+
+/// A request that was skipped because the client is in dry-run mode
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DryRunRequest {
+    /// HTTP method (e.g., "POST", "DELETE")
+    pub method: String,
+    /// Request path, relative to the base URL
+    pub path: String,
+    /// JSON request body, if any
+    pub body: Option<serde_json::Value>,
+}
+
+/// Circuit breaker settings for `VortexClient::with_circuit_breaker`
+///
+/// After `failure_threshold` consecutive failures (transport errors or 5xx
+/// responses) within `window`, requests fail fast with
+/// `VortexError::CircuitOpen` for `cooldown`. A single probe request is then
+/// let through; its success closes the circuit, its failure reopens it.
+///
+/// Durations (de)serialize as whole seconds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CircuitConfig {
+    /// Consecutive failures that open the circuit (default: 5)
+    pub failure_threshold: u32,
+    /// Failures further apart than this are not counted together (default: 30s)
+    #[serde(with = "duration_secs")]
+    pub window: Duration,
+    /// How long the circuit stays open before probing (default: 30s)
+    #[serde(with = "duration_secs")]
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            window: Duration::from_secs(30),
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+impl CircuitConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_failure_threshold(mut self, failure_threshold: u32) -> Self {
+        self.failure_threshold = failure_threshold;
+        self
+    }
+
+    pub fn with_window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+}
+
+/// Settings for `VortexClient::with_invitation_cache`
+///
+/// Cached invitations younger than `max_age` are served without a request.
+/// Older ones are revalidated with `If-None-Match`, and a `304 Not Modified`
+/// response refreshes the cached copy. Within `stale_while_revalidate` past
+/// `max_age`, the stale copy is served immediately and revalidated in the
+/// background instead.
+///
+/// Durations (de)serialize as whole seconds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CacheConfig {
+    /// How long a cached invitation is served without revalidating (default: 0)
+    #[serde(with = "duration_secs")]
+    pub max_age: Duration,
+    /// How long past `max_age` a stale invitation may be served while it is
+    /// revalidated in the background (default: 0, always revalidate first)
+    #[serde(with = "duration_secs")]
+    pub stale_while_revalidate: Duration,
+    /// Maximum number of invitations kept; the least recently validated one
+    /// is evicted first (default: 100)
+    pub max_entries: usize,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            max_age: Duration::ZERO,
+            stale_while_revalidate: Duration::ZERO,
+            max_entries: 100,
+        }
+    }
+}
+
+impl CacheConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    pub fn with_stale_while_revalidate(mut self, stale_while_revalidate: Duration) -> Self {
+        self.stale_while_revalidate = stale_while_revalidate;
+        self
+    }
+
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+}
+
+/// Serializable client settings, for building a `VortexClient` from a config file
+///
+/// The API key is deliberately not part of the config; pass it separately to
+/// `VortexClient::from_config`. Every field is optional, and durations
+/// (de)serialize as whole seconds.
+///
+/// # Example
+///
+/// ```
+/// use vortex_sdk::{VortexClient, VortexClientConfig};
+///
+/// let config: VortexClientConfig = serde_json::from_str(
+///     r#"{ "base_url": "https://api.vortexsoftware.com", "cache_ttl": 10 }"#,
+/// )
+/// .unwrap();
+/// let client = VortexClient::from_config("VRTX.xxx.yyy".to_string(), config);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VortexClientConfig {
+    /// API base URL; falls back to `VORTEX_API_BASE_URL`, then the production API
+    pub base_url: Option<String>,
+    /// Default request timeout
+    #[serde(with = "option_duration_secs")]
+    pub timeout: Option<Duration>,
+    pub auth_scheme: AuthScheme,
+    pub pool_max_idle_per_host: Option<usize>,
+    #[serde(with = "option_duration_secs")]
+    pub pool_idle_timeout: Option<Duration>,
+    #[serde(with = "option_duration_secs")]
+    pub tcp_keepalive: Option<Duration>,
+    pub http_version: HttpVersion,
+    /// Enables the invitation list cache with this TTL
+    #[serde(with = "option_duration_secs")]
+    pub cache_ttl: Option<Duration>,
+    /// Enables the per-invitation ETag cache for `get_invitation`
+    pub invitation_cache: Option<CacheConfig>,
+    pub circuit_breaker: Option<CircuitConfig>,
+    pub allowed_roles: Option<Vec<String>>,
+    pub default_role: Option<String>,
+    pub default_identifiers: Option<Vec<Identifier>>,
+    pub dry_run: bool,
+}
+
+/// (De)serialize a `Duration` as whole seconds
+mod duration_secs {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_secs())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_secs)
+    }
+}
+
+/// (De)serialize an `Option<Duration>` as whole seconds
+mod option_duration_secs {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(
+        duration: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match duration {
+            Some(duration) => serializer.serialize_some(&duration.as_secs()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        Option::<u64>::deserialize(deserializer).map(|secs| secs.map(Duration::from_secs))
+    }
+}
@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
+
+use crate::error::VortexError;
+use crate::webhooks::json_depth_exceeds;
 
 // ============================================================================
 // Webhook Event Type Constants
@@ -31,6 +35,27 @@ pub mod webhook_event_type {
 
     // Email
     pub const EMAIL_COMPLAINED: &str = "email.complained";
+
+    /// Every webhook event type defined in this module.
+    pub fn all() -> &'static [&'static str] {
+        &[
+            INVITATION_CREATED,
+            INVITATION_ACCEPTED,
+            INVITATION_DEACTIVATED,
+            INVITATION_EMAIL_DELIVERED,
+            INVITATION_EMAIL_BOUNCED,
+            INVITATION_EMAIL_OPENED,
+            INVITATION_LINK_CLICKED,
+            INVITATION_REMINDER_SENT,
+            DEPLOYMENT_CREATED,
+            DEPLOYMENT_DEACTIVATED,
+            ABTEST_STARTED,
+            ABTEST_WINNER_DECLARED,
+            MEMBER_CREATED,
+            GROUP_MEMBER_ADDED,
+            EMAIL_COMPLAINED,
+        ]
+    }
 }
 
 /// Analytics event type constants for behavioral telemetry.
@@ -40,6 +65,17 @@ pub mod analytics_event_type {
     pub const INVITATION_CLICKED: &str = "invitation_clicked";
     pub const INVITATION_ACCEPTED: &str = "invitation_accepted";
     pub const SHARE_TRIGGERED: &str = "share_triggered";
+
+    /// Every analytics event type defined in this module.
+    pub fn all() -> &'static [&'static str] {
+        &[
+            WIDGET_LOADED,
+            INVITATION_SENT,
+            INVITATION_CLICKED,
+            INVITATION_ACCEPTED,
+            SHARE_TRIGGERED,
+        ]
+    }
 }
 
 // ============================================================================
@@ -61,6 +97,113 @@ pub struct VortexWebhookEvent {
     pub data: HashMap<String, serde_json::Value>,
 }
 
+/// Database operation that produced a webhook event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operation {
+    Insert,
+    Update,
+    Delete,
+    Unknown(String),
+}
+
+impl From<&str> for Operation {
+    fn from(value: &str) -> Self {
+        match value {
+            "insert" => Operation::Insert,
+            "update" => Operation::Update,
+            "delete" => Operation::Delete,
+            other => Operation::Unknown(other.to_string()),
+        }
+    }
+}
+
+/// Source table that produced a webhook event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SourceTable {
+    Invitations,
+    Deployments,
+    Members,
+    Groups,
+    Unknown(String),
+}
+
+impl From<&str> for SourceTable {
+    fn from(value: &str) -> Self {
+        match value {
+            "invitations" => SourceTable::Invitations,
+            "deployments" => SourceTable::Deployments,
+            "members" => SourceTable::Members,
+            "groups" => SourceTable::Groups,
+            other => SourceTable::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl VortexWebhookEvent {
+    /// The typed `operation` field.
+    pub fn operation_kind(&self) -> Operation {
+        Operation::from(self.operation.as_str())
+    }
+
+    /// The typed `source_table` field.
+    pub fn source_table_kind(&self) -> SourceTable {
+        SourceTable::from(self.source_table.as_str())
+    }
+
+    /// Returns true if the event moves an invitation to a terminal state.
+    ///
+    /// Only `invitation.accepted` and `invitation.deactivated` are terminal.
+    /// Intermediate invitation events (delivery, opens, clicks, reminders),
+    /// non-invitation events and event types unknown to this SDK version
+    /// return false.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self.event_type.as_str(),
+            webhook_event_type::INVITATION_ACCEPTED | webhook_event_type::INVITATION_DEACTIVATED
+        )
+    }
+
+    /// Interpret an engagement event's `data` as an [`EngagementDelta`].
+    ///
+    /// Returns `None` for non-engagement event types or if `data` has no
+    /// `invitationId`. Counts are read from `data` when present; otherwise the
+    /// event itself counts as one click (`invitation.link.clicked`) or one open
+    /// (`invitation.email.opened`).
+    pub fn engagement_delta(&self) -> Option<EngagementDelta> {
+        let (default_clicks, default_opens) = match self.event_type.as_str() {
+            webhook_event_type::INVITATION_LINK_CLICKED => (1, 0),
+            webhook_event_type::INVITATION_EMAIL_OPENED => (0, 1),
+            _ => return None,
+        };
+
+        let invitation_id = self.data.get("invitationId")?.as_str()?.to_string();
+        let count = |key: &str, default: u32| {
+            self.data
+                .get(key)
+                .and_then(|v| v.as_u64())
+                .and_then(|v| u32::try_from(v).ok())
+                .unwrap_or(default)
+        };
+
+        Some(EngagementDelta {
+            invitation_id,
+            clicks: count("clicks", default_clicks),
+            views: count("views", 0),
+            opens: count("opens", default_opens),
+        })
+    }
+}
+
+/// Engagement counts carried by an engagement webhook event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EngagementDelta {
+    pub invitation_id: String,
+    pub clicks: u32,
+    pub views: u32,
+    pub opens: u32,
+}
+
 /// An analytics event representing client-side behavioral telemetry.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -81,6 +224,37 @@ pub struct VortexAnalyticsEvent {
     pub timestamp: String,
 }
 
+impl fmt::Display for VortexWebhookEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} at {}", self.id, self.event_type, self.timestamp)
+    }
+}
+
+impl VortexAnalyticsEvent {
+    /// Parse the JSON-encoded `segmentation` string into `T`.
+    ///
+    /// Returns `Ok(None)` when there is no segmentation and a
+    /// `VortexError::SerializationError` when it is not valid JSON for `T`.
+    /// Use `serde_json::Value` for `T` to get untyped data.
+    pub fn segmentation_parsed<T: serde::de::DeserializeOwned>(
+        &self,
+    ) -> Result<Option<T>, VortexError> {
+        self.segmentation
+            .as_deref()
+            .map(serde_json::from_str)
+            .transpose()
+            .map_err(|e| {
+                VortexError::SerializationError(format!("Failed to parse segmentation: {}", e))
+            })
+    }
+}
+
+impl fmt::Display for VortexAnalyticsEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} at {}", self.id, self.name, self.timestamp)
+    }
+}
+
 /// Any event delivered to a Vortex webhook endpoint.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -89,7 +263,26 @@ pub enum VortexEvent {
     Analytics(VortexAnalyticsEvent),
 }
 
+impl fmt::Display for VortexEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VortexEvent::Webhook(e) => e.fmt(f),
+            VortexEvent::Analytics(e) => e.fmt(f),
+        }
+    }
+}
+
 impl VortexEvent {
+    /// Parse an event from a JSON body without verifying its signature.
+    ///
+    /// Intended for fixtures and for bodies already verified by another
+    /// layer; use `VortexWebhooks::construct_event` for untrusted requests.
+    pub fn from_json(json: &str) -> Result<Self, VortexError> {
+        serde_json::from_str(json).map_err(|e| {
+            VortexError::SerializationError(format!("Failed to parse webhook payload: {}", e))
+        })
+    }
+
     /// Returns true if this is a webhook event.
     pub fn is_webhook_event(&self) -> bool {
         matches!(self, VortexEvent::Webhook(_))
@@ -116,3 +309,56 @@ impl VortexEvent {
         }
     }
 }
+
+// ============================================================================
+// Verification Failures
+// ============================================================================
+
+/// Details about a webhook payload that failed signature verification.
+///
+/// The `untrusted_*` fields are read from the unverified body and may be forged.
+#[derive(Debug, Clone)]
+pub struct WebhookVerifyFailure {
+    /// Length of the raw payload in bytes.
+    pub payload_len: usize,
+    /// The `id` field of the payload, if it parsed as JSON within the depth limit. Untrusted.
+    pub untrusted_id: Option<String>,
+    /// The `type` field of the payload, if it parsed as JSON within the depth limit. Untrusted.
+    pub untrusted_type: Option<String>,
+}
+
+impl WebhookVerifyFailure {
+    /// Read the untrusted fields, skipping them if the payload nests deeper than `max_depth`
+    pub(crate) fn from_payload(payload: &[u8], max_depth: Option<usize>) -> Self {
+        /// Only the fields reported; everything else in the body is skipped while parsing
+        #[derive(Deserialize)]
+        struct UntrustedFields {
+            #[serde(default)]
+            id: Option<serde_json::Value>,
+            #[serde(default, rename = "type")]
+            event_type: Option<serde_json::Value>,
+        }
+
+        let too_deep = max_depth.is_some_and(|max| json_depth_exceeds(payload, max));
+        let fields: Option<UntrustedFields> = if too_deep {
+            None
+        } else {
+            serde_json::from_slice(payload).ok()
+        };
+        let string = |value: Option<serde_json::Value>| match value {
+            Some(serde_json::Value::String(value)) => Some(value),
+            _ => None,
+        };
+
+        let (untrusted_id, untrusted_type) = match fields {
+            Some(fields) => (string(fields.id), string(fields.event_type)),
+            None => (None, None),
+        };
+
+        Self {
+            payload_len: payload.len(),
+            untrusted_id,
+            untrusted_type,
+        }
+    }
+}
@@ -1,11 +1,15 @@
 use hmac::{Hmac, Mac};
 use sha2::Sha256;
+use std::sync::{Arc, RwLock};
 
-use crate::error::VortexError;
-use crate::webhook_types::VortexEvent;
+use crate::error::{SignatureErrorKind, VortexError};
+use crate::types::OneOrMany;
+use crate::webhook_types::{VortexEvent, WebhookVerifyFailure};
 
 type HmacSha256 = Hmac<Sha256>;
 
+type VerifyFailureCallback = Arc<dyn Fn(&WebhookVerifyFailure) + Send + Sync>;
+
 /// Vortex webhook verification and parsing.
 ///
 /// # Example
@@ -16,7 +20,11 @@ type HmacSha256 = Hmac<Sha256>;
 /// let webhooks = VortexWebhooks::new("whsec_your_secret").unwrap();
 /// ```
 pub struct VortexWebhooks {
-    secret: String,
+    secrets: RwLock<Vec<String>>,
+    on_verify_failure: Option<VerifyFailureCallback>,
+    max_payload_bytes: Option<usize>,
+    max_json_depth: Option<usize>,
+    lenient_hex: bool,
 }
 
 impl VortexWebhooks {
@@ -24,30 +32,223 @@ impl VortexWebhooks {
     ///
     /// # Errors
     ///
-    /// Returns `VortexError::WebhookSignatureError` if the secret is empty.
+    /// Returns `VortexError::WebhookSignatureError` if the secret is empty or
+    /// does not start with `whsec_`.
     pub fn new(secret: impl Into<String>) -> Result<Self, VortexError> {
         let secret = secret.into();
-        if secret.is_empty() {
-            return Err(VortexError::WebhookSignatureError(
-                "Webhook secret must not be empty.".into(),
+        check_secret(&secret)?;
+        Ok(Self::from_secret(secret))
+    }
+
+    /// Create a new webhook verifier without checking the `whsec_` prefix.
+    ///
+    /// Use this only for legacy secrets that predate the prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VortexError::WebhookSignatureError` if the secret is empty.
+    pub fn new_unchecked(secret: impl Into<String>) -> Result<Self, VortexError> {
+        let secret = secret.into();
+        check_secret_not_empty(&secret)?;
+        Ok(Self::from_secret(secret))
+    }
+
+    fn from_secret(secret: String) -> Self {
+        Self {
+            secrets: RwLock::new(vec![secret]),
+            on_verify_failure: None,
+            max_payload_bytes: None,
+            max_json_depth: None,
+            lenient_hex: false,
+        }
+    }
+
+    /// Add a signing secret, e.g. the new secret during rotation.
+    ///
+    /// Payloads signed with any current secret are accepted. Safe to call while
+    /// other threads are verifying.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vortex_sdk::VortexWebhooks;
+    ///
+    /// let webhooks = VortexWebhooks::new("whsec_old").unwrap();
+    ///
+    /// // Accept both secrets while senders switch over...
+    /// webhooks.add_secret("whsec_new").unwrap();
+    ///
+    /// // ...then drop the old one.
+    /// webhooks.remove_secret("whsec_old").unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `VortexError::WebhookSignatureError` if the secret is empty or
+    /// does not start with `whsec_`.
+    pub fn add_secret(&self, secret: impl Into<String>) -> Result<(), VortexError> {
+        let secret = secret.into();
+        check_secret(&secret)?;
+        let mut secrets = self.secrets.write().unwrap_or_else(|e| e.into_inner());
+        if !secrets.contains(&secret) {
+            secrets.push(secret);
+        }
+        Ok(())
+    }
+
+    /// Remove a signing secret. Returns whether the secret was present.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VortexError::WebhookSignatureError` if this would remove the last secret.
+    pub fn remove_secret(&self, secret: &str) -> Result<bool, VortexError> {
+        let mut secrets = self.secrets.write().unwrap_or_else(|e| e.into_inner());
+        if !secrets.iter().any(|s| s == secret) {
+            return Ok(false);
+        }
+        if secrets.len() == 1 {
+            return Err(VortexError::signature(
+                SignatureErrorKind::EmptySecret,
+                "Cannot remove the last webhook secret.",
             ));
         }
-        Ok(Self { secret })
+        secrets.retain(|s| s != secret);
+        Ok(true)
+    }
+
+    /// Replace all signing secrets at once.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VortexError::WebhookSignatureError` if the list is empty or any
+    /// secret is empty or lacks the `whsec_` prefix.
+    pub fn set_secrets(&self, new_secrets: Vec<String>) -> Result<(), VortexError> {
+        if new_secrets.is_empty() {
+            return Err(VortexError::signature(
+                SignatureErrorKind::EmptySecret,
+                "At least one webhook secret is required.",
+            ));
+        }
+        for secret in &new_secrets {
+            check_secret(secret)?;
+        }
+        *self.secrets.write().unwrap_or_else(|e| e.into_inner()) = new_secrets;
+        Ok(())
+    }
+
+    /// Set a callback invoked when `construct_event` fails signature verification.
+    ///
+    /// The callback receives the payload length and, if the body parses as JSON,
+    /// its `id` and `type` fields. These fields are **untrusted**: the signature
+    /// did not verify, so the body may have been forged or tampered with. Use
+    /// them only for logging and correlation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vortex_sdk::VortexWebhooks;
+    ///
+    /// let webhooks = VortexWebhooks::new("whsec_your_secret")
+    ///     .unwrap()
+    ///     .with_on_verify_failure(|failure| {
+    ///         eprintln!(
+    ///             "Webhook verification failed: len={} id={:?} type={:?}",
+    ///             failure.payload_len, failure.untrusted_id, failure.untrusted_type
+    ///         );
+    ///     });
+    /// ```
+    pub fn with_on_verify_failure<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&WebhookVerifyFailure) + Send + Sync + 'static,
+    {
+        self.on_verify_failure = Some(Arc::new(callback));
+        self
+    }
+
+    /// Reject payloads larger than `max` bytes before verifying or parsing them.
+    ///
+    /// Oversized payloads fail `verify_signature`, and `construct_event` returns
+    /// `VortexError::WebhookSignatureError("payload too large")` without hashing
+    /// or parsing the body. Unlimited by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vortex_sdk::VortexWebhooks;
+    ///
+    /// let webhooks = VortexWebhooks::new("whsec_your_secret")
+    ///     .unwrap()
+    ///     .with_max_payload_bytes(256 * 1024)
+    ///     .with_max_json_depth(32);
+    /// ```
+    pub fn with_max_payload_bytes(mut self, max: usize) -> Self {
+        self.max_payload_bytes = Some(max);
+        self
+    }
+
+    /// Reject verified payloads whose JSON nests objects and arrays deeper than `max`.
+    ///
+    /// The depth is checked with a linear scan before the payload is parsed;
+    /// `construct_event` returns a `VortexError::SerializationError` when it is
+    /// exceeded. Unlimited by default (beyond `serde_json`'s own recursion limit).
+    pub fn with_max_json_depth(mut self, max: usize) -> Self {
+        self.max_json_depth = Some(max);
+        self
+    }
+
+    /// Accept hex signatures with stripped leading zeros or an odd length.
+    ///
+    /// Signatures are decoded strictly by default. With this enabled, a
+    /// signature shorter than 64 hex digits (or of odd length) is left-padded
+    /// with zeros before decoding. Only enable this for non-conforming signers
+    /// or proxies that rewrite the signature header.
+    pub fn with_lenient_hex(mut self, lenient: bool) -> Self {
+        self.lenient_hex = lenient;
+        self
     }
 
     /// Verify the HMAC-SHA256 signature of an incoming webhook payload.
     ///
+    /// Returns true if the signature matches any current secret.
     /// Uses constant-time comparison to prevent timing attacks.
+    /// Payloads over the configured maximum size never verify.
     pub fn verify_signature(&self, payload: &[u8], signature: &str) -> bool {
-        let Ok(mut mac) = HmacSha256::new_from_slice(self.secret.as_bytes()) else {
-            return false;
-        };
-        mac.update(payload);
+        !self.is_too_large(payload) && self.check_signature(payload, signature).is_ok()
+    }
+
+    /// Verify an HMAC-SHA256 signature that has already been hex-decoded.
+    ///
+    /// Same as `verify_signature`, for callers that receive the raw 32-byte
+    /// MAC instead of the `X-Vortex-Signature` hex string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vortex_sdk::VortexWebhooks;
+    ///
+    /// let webhooks = VortexWebhooks::new("whsec_your_secret").unwrap();
+    /// assert!(!webhooks.verify_signature_bytes(b"{}", &[0u8; 32]));
+    /// ```
+    pub fn verify_signature_bytes(&self, payload: &[u8], signature: &[u8]) -> bool {
+        !self.is_too_large(payload) && self.matches_any_secret(payload, signature)
+    }
 
-        let expected = hex_encode(mac.finalize().into_bytes().as_slice());
+    /// Decode the signature and check it against every secret
+    fn check_signature(&self, payload: &[u8], signature: &str) -> Result<(), SignatureErrorKind> {
+        let signature = decode_signature(signature, self.lenient_hex)
+            .ok_or(SignatureErrorKind::Malformed)?;
+        if self.matches_any_secret(payload, &signature) {
+            Ok(())
+        } else {
+            Err(SignatureErrorKind::Mismatch)
+        }
+    }
 
-        // Constant-time comparison
-        constant_time_eq(expected.as_bytes(), signature.as_bytes())
+    fn matches_any_secret(&self, payload: &[u8], signature: &[u8]) -> bool {
+        let secrets = self.secrets.read().unwrap_or_else(|e| e.into_inner());
+        secrets
+            .iter()
+            .any(|secret| verify_with_secret(secret, payload, signature))
     }
 
     /// Verify and parse an incoming webhook payload.
@@ -60,21 +261,324 @@ impl VortexWebhooks {
     /// * `payload` - The raw request body bytes
     /// * `signature` - The value of the `X-Vortex-Signature` header
     pub fn construct_event(&self, payload: &[u8], signature: &str) -> Result<VortexEvent, VortexError> {
-        if !self.verify_signature(payload, signature) {
-            return Err(VortexError::WebhookSignatureError(
-                "Webhook signature verification failed. Ensure you are using the raw request body and the correct signing secret.".into(),
-            ));
+        self.verify_payload(payload, signature)?;
+
+        serde_json::from_slice(payload).map_err(|e| {
+            VortexError::SerializationError(format!("Failed to parse webhook payload: {}", e))
+        })
+    }
+
+    /// Verify and parse a webhook from the request headers and raw body.
+    ///
+    /// Reads the signature from the `X-Vortex-Signature` header, so handlers
+    /// don't need to spell out the header name. Accepts `http` 0.2 header
+    /// maps (as used by `reqwest` 0.11 and actix-web 4) and, with the `http`
+    /// feature, `http` 1.x header maps (as used by axum 0.7 and hyper 1).
+    ///
+    /// # Errors
+    ///
+    /// Returns `VortexError::WebhookSignatureError` with kind `Malformed` if
+    /// the header is missing or not valid UTF-8, and otherwise fails like
+    /// `construct_event`.
+    pub fn construct_event_from_parts<H: WebhookHeaders + ?Sized>(
+        &self,
+        headers: &H,
+        body: &[u8],
+    ) -> Result<VortexEvent, VortexError> {
+        let signature = headers.header_bytes(WEBHOOK_SIGNATURE_HEADER).ok_or_else(|| {
+            VortexError::signature(
+                SignatureErrorKind::Malformed,
+                "Missing X-Vortex-Signature header.",
+            )
+        })?;
+        let signature = std::str::from_utf8(signature).map_err(|_| {
+            VortexError::signature(
+                SignatureErrorKind::Malformed,
+                "X-Vortex-Signature header is not valid UTF-8.",
+            )
+        })?;
+        self.construct_event(body, signature)
+    }
+
+    /// Verify and parse a webhook payload, then map the event with `f`.
+    ///
+    /// Shorthand for `construct_event` followed by `f`, so a handler can turn
+    /// the event into a domain type with a single `VortexError` path.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use vortex_sdk::{VortexError, VortexWebhooks};
+    ///
+    /// # fn example(payload: &[u8], signature: &str) -> Result<(), VortexError> {
+    /// let webhooks = VortexWebhooks::new("whsec_your_secret")?;
+    /// let event_id = webhooks.verify_and_then(payload, signature, |event| {
+    ///     let event = event
+    ///         .as_webhook_event()
+    ///         .ok_or_else(|| VortexError::SerializationError("expected a webhook event".into()))?;
+    ///     Ok(event.id.clone())
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn verify_and_then<T, F>(
+        &self,
+        payload: &[u8],
+        signature: &str,
+        f: F,
+    ) -> Result<T, VortexError>
+    where
+        F: FnOnce(VortexEvent) -> Result<T, VortexError>,
+    {
+        self.construct_event(payload, signature).and_then(f)
+    }
+
+    /// Parse a webhook payload without verifying its signature.
+    ///
+    /// Applies the same size and nesting limits as `construct_event`, so it
+    /// is safe to call on untrusted bytes: malformed input always yields an
+    /// `Err`, never a panic. Since no signature is checked, limit violations
+    /// are reported as `SerializationError`. Only use the result for
+    /// inspection or testing; trust events from `construct_event`.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - The raw request body bytes
+    pub fn try_parse(&self, payload: &[u8]) -> Result<VortexEvent, VortexError> {
+        if self.is_too_large(payload) {
+            return Err(VortexError::SerializationError(format!(
+                "Webhook payload is larger than {} bytes",
+                self.max_payload_bytes.unwrap_or_default()
+            )));
         }
+        self.check_depth(payload)?;
 
         serde_json::from_slice(payload).map_err(|e| {
             VortexError::SerializationError(format!("Failed to parse webhook payload: {}", e))
         })
     }
+
+    /// Verify and parse a webhook payload holding one event or an array of events.
+    ///
+    /// The signature is verified over the whole body, which may be a single
+    /// event object or a JSON array mixing invitation and analytics events.
+    /// A single event is returned as a one-element vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - The raw request body bytes
+    /// * `signature` - The value of the `X-Vortex-Signature` header
+    pub fn construct_events(
+        &self,
+        payload: &[u8],
+        signature: &str,
+    ) -> Result<Vec<VortexEvent>, VortexError> {
+        self.verify_payload(payload, signature)?;
+
+        let events: OneOrMany<VortexEvent> = serde_json::from_slice(payload).map_err(|e| {
+            VortexError::SerializationError(format!("Failed to parse webhook payload: {}", e))
+        })?;
+        Ok(events.into_vec())
+    }
+
+    /// Enforce the size limit, verify the signature and enforce the depth limit
+    fn verify_payload(&self, payload: &[u8], signature: &str) -> Result<(), VortexError> {
+        if self.is_too_large(payload) {
+            return Err(VortexError::signature(
+                SignatureErrorKind::PayloadTooLarge,
+                "payload too large",
+            ));
+        }
+
+        if let Err(kind) = self.check_signature(payload, signature) {
+            if let Some(callback) = &self.on_verify_failure {
+                callback(&WebhookVerifyFailure::from_payload(payload, self.max_json_depth));
+            }
+            let message = match kind {
+                SignatureErrorKind::Malformed => {
+                    "Webhook signature is not valid hex. Pass the X-Vortex-Signature header value unchanged."
+                }
+                _ => {
+                    "Webhook signature verification failed. Ensure you are using the raw request body and the correct signing secret."
+                }
+            };
+            return Err(VortexError::signature(kind, message));
+        }
+
+        self.check_depth(payload)
+    }
+
+    fn check_depth(&self, payload: &[u8]) -> Result<(), VortexError> {
+        if let Some(max) = self.max_json_depth {
+            if json_depth_exceeds(payload, max) {
+                return Err(VortexError::SerializationError(format!(
+                    "Webhook payload nests deeper than {} levels",
+                    max
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_too_large(&self, payload: &[u8]) -> bool {
+        self.max_payload_bytes.is_some_and(|max| payload.len() > max)
+    }
 }
 
-/// Hex-encode bytes (lowercase).
-fn hex_encode(bytes: &[u8]) -> String {
-    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+/// Request header maps that `VortexWebhooks::construct_event_from_parts` can read.
+pub trait WebhookHeaders {
+    /// The raw value of the first header called `name`, if present
+    fn header_bytes(&self, name: &str) -> Option<&[u8]>;
+}
+
+impl WebhookHeaders for reqwest::header::HeaderMap {
+    fn header_bytes(&self, name: &str) -> Option<&[u8]> {
+        self.get(name).map(|value| value.as_bytes())
+    }
+}
+
+#[cfg(feature = "http")]
+impl WebhookHeaders for http::HeaderMap {
+    fn header_bytes(&self, name: &str) -> Option<&[u8]> {
+        self.get(name).map(|value| value.as_bytes())
+    }
+}
+
+/// Verify a webhook signature without constructing a [`VortexWebhooks`].
+///
+/// Returns false for an empty secret. Prefer `VortexWebhooks` when verifying
+/// many payloads or when you need secret rotation.
+///
+/// # Example
+///
+/// ```
+/// let valid = vortex_sdk::verify_webhook_signature("whsec_your_secret", b"{}", "bad_sig");
+/// assert!(!valid);
+/// ```
+pub fn verify_webhook_signature(secret: &str, payload: &[u8], signature: &str) -> bool {
+    !secret.is_empty()
+        && decode_signature(signature, false)
+            .is_some_and(|signature| verify_with_secret(secret, payload, &signature))
+}
+
+/// Verify and parse a webhook payload without constructing a [`VortexWebhooks`].
+///
+/// Equivalent to `VortexWebhooks::new(secret)?.construct_event(payload, signature)`.
+pub fn construct_webhook_event(
+    secret: &str,
+    payload: &[u8],
+    signature: &str,
+) -> Result<VortexEvent, VortexError> {
+    VortexWebhooks::new(secret)?.construct_event(payload, signature)
+}
+
+/// Name of the header carrying the webhook signature, lowercase as HTTP/2 requires.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "x-vortex-signature";
+
+/// Expected prefix of Vortex webhook signing secrets.
+const SECRET_PREFIX: &str = "whsec_";
+
+/// Reject empty secrets.
+fn check_secret_not_empty(secret: &str) -> Result<(), VortexError> {
+    if secret.is_empty() {
+        return Err(VortexError::signature(
+            SignatureErrorKind::EmptySecret,
+            "Webhook secret must not be empty.",
+        ));
+    }
+    Ok(())
+}
+
+/// Reject empty secrets and secrets without the `whsec_` prefix.
+fn check_secret(secret: &str) -> Result<(), VortexError> {
+    check_secret_not_empty(secret)?;
+    if !secret.starts_with(SECRET_PREFIX) {
+        let hint = if secret.starts_with("VRTX.") {
+            " This looks like an API key; use the webhook signing secret instead."
+        } else {
+            " Use VortexWebhooks::new_unchecked for legacy secrets without the prefix."
+        };
+        return Err(VortexError::signature(
+            SignatureErrorKind::InvalidSecret,
+            format!("Webhook secret must start with \"{}\".{}", SECRET_PREFIX, hint),
+        ));
+    }
+    Ok(())
+}
+
+/// Verify a decoded signature against a single secret.
+fn verify_with_secret(secret: &str, payload: &[u8], signature: &[u8]) -> bool {
+    let Ok(mut mac) = HmacSha256::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(payload);
+
+    // Constant-time comparison
+    constant_time_eq(mac.finalize().into_bytes().as_slice(), signature)
+}
+
+/// Length of a hex-encoded HMAC-SHA256 signature.
+const SIGNATURE_HEX_LEN: usize = 64;
+
+/// Decode a hex signature, optionally left-padding short or odd-length input.
+fn decode_signature(signature: &str, lenient: bool) -> Option<Vec<u8>> {
+    let padded;
+    let hex = if lenient && (signature.len() < SIGNATURE_HEX_LEN || signature.len() % 2 == 1) {
+        let width = SIGNATURE_HEX_LEN.max(signature.len() + 1) & !1;
+        padded = format!("{:0>width$}", signature, width = width);
+        padded.as_str()
+    } else {
+        signature
+    };
+    hex_decode(hex)
+}
+
+/// Strictly decode hex (either case); `None` for odd length or non-hex digits.
+fn hex_decode(hex: &str) -> Option<Vec<u8>> {
+    let pairs = hex.as_bytes().chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return None;
+    }
+    pairs
+        .map(|pair| {
+            let digits = std::str::from_utf8(pair).ok()?;
+            if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return None;
+            }
+            u8::from_str_radix(digits, 16).ok()
+        })
+        .collect()
+}
+
+/// Whether `payload` nests objects/arrays more than `max` levels deep, ignoring brackets in strings
+pub(crate) fn json_depth_exceeds(payload: &[u8], max: usize) -> bool {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for &byte in payload {
+        if in_string {
+            match byte {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match byte {
+            b'"' => in_string = true,
+            b'{' | b'[' => {
+                depth += 1;
+                if depth > max {
+                    return true;
+                }
+            }
+            b'}' | b']' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+    false
 }
 
 /// Constant-time byte comparison.
@@ -92,9 +596,15 @@ fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
 
     const TEST_SECRET: &str = "whsec_test_secret";
 
+    /// Hex-encode bytes (lowercase).
+    fn hex_encode(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
     fn sign(payload: &[u8]) -> String {
         let mut mac = HmacSha256::new_from_slice(TEST_SECRET.as_bytes()).unwrap();
         mac.update(payload);
@@ -126,6 +636,33 @@ mod tests {
         assert!(!webhooks.verify_signature(tampered.as_bytes(), &sig));
     }
 
+    #[test]
+    fn test_secret_prefix_validation() {
+        assert!(VortexWebhooks::new("").is_err());
+        assert!(VortexWebhooks::new("legacy_secret").is_err());
+        assert!(VortexWebhooks::new("VRTX.abc.def").is_err());
+        assert!(VortexWebhooks::new_unchecked("legacy_secret").is_ok());
+        assert!(VortexWebhooks::new_unchecked("").is_err());
+    }
+
+    #[test]
+    fn test_secret_rotation() {
+        let webhooks = VortexWebhooks::new("whsec_old").unwrap();
+        let sig = sign(SAMPLE_WEBHOOK.as_bytes());
+        assert!(!webhooks.verify_signature(SAMPLE_WEBHOOK.as_bytes(), &sig));
+
+        webhooks.add_secret(TEST_SECRET).unwrap();
+        assert!(webhooks.verify_signature(SAMPLE_WEBHOOK.as_bytes(), &sig));
+
+        assert!(webhooks.remove_secret("whsec_old").unwrap());
+        assert!(webhooks.verify_signature(SAMPLE_WEBHOOK.as_bytes(), &sig));
+        assert!(webhooks.remove_secret(TEST_SECRET).is_err());
+
+        webhooks.set_secrets(vec!["whsec_other".to_string()]).unwrap();
+        assert!(!webhooks.verify_signature(SAMPLE_WEBHOOK.as_bytes(), &sig));
+        assert!(webhooks.set_secrets(vec![]).is_err());
+    }
+
     #[test]
     fn test_construct_webhook_event() {
         let webhooks = VortexWebhooks::new(TEST_SECRET).unwrap();
@@ -134,6 +671,225 @@ mod tests {
         assert!(event.is_webhook_event());
         let wh = event.as_webhook_event().unwrap();
         assert_eq!(wh.event_type, "invitation.accepted");
+        assert_eq!(wh.operation_kind(), crate::webhook_types::Operation::Update);
+        assert_eq!(wh.source_table_kind(), crate::webhook_types::SourceTable::Invitations);
+        assert_eq!(event.to_string(), "evt_1 invitation.accepted at 2026-02-25T12:00:00Z");
+    }
+
+    #[test]
+    fn test_engagement_delta() {
+        let clicked = SAMPLE_WEBHOOK
+            .replace("invitation.accepted", "invitation.link.clicked")
+            .replace(r#""targetEmail":"user@test.com""#, r#""invitationId":"inv_1""#);
+        let webhooks = VortexWebhooks::new(TEST_SECRET).unwrap();
+        let event = webhooks.construct_event(clicked.as_bytes(), &sign(clicked.as_bytes())).unwrap();
+        let delta = event.as_webhook_event().unwrap().engagement_delta().unwrap();
+        assert_eq!(delta.invitation_id, "inv_1");
+        assert_eq!((delta.clicks, delta.views, delta.opens), (1, 0, 0));
+
+        let event = webhooks
+            .construct_event(SAMPLE_WEBHOOK.as_bytes(), &sign(SAMPLE_WEBHOOK.as_bytes()))
+            .unwrap();
+        assert!(event.as_webhook_event().unwrap().engagement_delta().is_none());
+    }
+
+    #[test]
+    fn test_construct_event_from_parts() {
+        let webhooks = VortexWebhooks::new(TEST_SECRET).unwrap();
+        let payload = SAMPLE_WEBHOOK.as_bytes();
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        let err = webhooks.construct_event_from_parts(&headers, payload).unwrap_err();
+        assert_eq!(err.signature_error_kind(), Some(SignatureErrorKind::Malformed));
+
+        headers.insert("X-Vortex-Signature", sign(payload).parse().unwrap());
+        assert!(webhooks.construct_event_from_parts(&headers, payload).is_ok());
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn test_construct_event_from_http1_parts() {
+        let webhooks = VortexWebhooks::new(TEST_SECRET).unwrap();
+        let payload = SAMPLE_WEBHOOK.as_bytes();
+
+        let mut headers = http::HeaderMap::new();
+        headers.insert("X-Vortex-Signature", sign(payload).parse().unwrap());
+        assert!(webhooks.construct_event_from_parts(&headers, payload).is_ok());
+    }
+
+    #[test]
+    fn test_verify_signature_bytes() {
+        let webhooks = VortexWebhooks::new(TEST_SECRET).unwrap();
+        let payload = SAMPLE_WEBHOOK.as_bytes();
+        let mac = hex_decode(&sign(payload)).unwrap();
+        assert!(webhooks.verify_signature_bytes(payload, &mac));
+        assert!(!webhooks.verify_signature_bytes(payload, &mac[..31]));
+        assert!(!webhooks.verify_signature_bytes(b"{}", &mac));
+
+        let limited = VortexWebhooks::new(TEST_SECRET).unwrap().with_max_payload_bytes(8);
+        assert!(!limited.verify_signature_bytes(payload, &mac));
+    }
+
+    #[test]
+    fn test_signature_error_kinds() {
+        let kind = |result: Result<_, VortexError>| result.unwrap_err().signature_error_kind();
+        assert_eq!(
+            kind(VortexWebhooks::new("").map(|_| ())),
+            Some(SignatureErrorKind::EmptySecret)
+        );
+        assert_eq!(
+            kind(VortexWebhooks::new("VRTX.key").map(|_| ())),
+            Some(SignatureErrorKind::InvalidSecret)
+        );
+
+        let webhooks = VortexWebhooks::new(TEST_SECRET).unwrap();
+        let payload = SAMPLE_WEBHOOK.as_bytes();
+        assert_eq!(
+            kind(webhooks.construct_event(payload, "not hex").map(|_| ())),
+            Some(SignatureErrorKind::Malformed)
+        );
+        assert_eq!(
+            kind(webhooks.construct_event(payload, &"ab".repeat(32)).map(|_| ())),
+            Some(SignatureErrorKind::Mismatch)
+        );
+        assert_eq!(
+            kind(webhooks.remove_secret(TEST_SECRET).map(|_| ())),
+            Some(SignatureErrorKind::EmptySecret)
+        );
+    }
+
+    #[test]
+    fn test_construct_events_mixed_batch() {
+        let webhooks = VortexWebhooks::new(TEST_SECRET).unwrap();
+        let batch = format!("[{},{}]", SAMPLE_WEBHOOK, SAMPLE_ANALYTICS);
+        let events = webhooks.construct_events(batch.as_bytes(), &sign(batch.as_bytes())).unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(events[0].is_webhook_event());
+        assert!(events[1].is_analytics_event());
+
+        let single = webhooks
+            .construct_events(SAMPLE_WEBHOOK.as_bytes(), &sign(SAMPLE_WEBHOOK.as_bytes()))
+            .unwrap();
+        assert_eq!(single.len(), 1);
+
+        assert!(webhooks.construct_events(batch.as_bytes(), "bad_sig").is_err());
+    }
+
+    #[test]
+    fn test_verify_and_then() {
+        let webhooks = VortexWebhooks::new(TEST_SECRET).unwrap();
+        let payload = SAMPLE_WEBHOOK.as_bytes();
+
+        let id = webhooks
+            .verify_and_then(payload, &sign(payload), |event| {
+                Ok(event.as_webhook_event().unwrap().id.clone())
+            })
+            .unwrap();
+        assert_eq!(id, "evt_1");
+
+        let result = webhooks.verify_and_then(payload, "00", |_| Ok(()));
+        assert!(matches!(result, Err(VortexError::WebhookSignatureError(_))));
+
+        let result: Result<(), _> = webhooks.verify_and_then(payload, &sign(payload), |_| {
+            Err(VortexError::InvalidRequest("rejected".into()))
+        });
+        assert!(matches!(result, Err(VortexError::InvalidRequest(_))));
+    }
+
+    #[test]
+    fn test_is_terminal() {
+        use crate::webhook_types::webhook_event_type;
+
+        let terminal: Vec<&str> = webhook_event_type::all()
+            .iter()
+            .copied()
+            .filter(|event_type| {
+                let payload = SAMPLE_WEBHOOK.replace("invitation.accepted", event_type);
+                let event = VortexEvent::from_json(&payload).unwrap();
+                event.as_webhook_event().unwrap().is_terminal()
+            })
+            .collect();
+        assert_eq!(terminal, vec!["invitation.accepted", "invitation.deactivated"]);
+    }
+
+    #[test]
+    fn test_event_type_lists() {
+        use crate::webhook_types::{analytics_event_type, webhook_event_type};
+
+        let webhook_types = webhook_event_type::all();
+        assert!(webhook_types.contains(&webhook_event_type::INVITATION_ACCEPTED));
+        assert!(webhook_types.contains(&webhook_event_type::EMAIL_COMPLAINED));
+        let unique: std::collections::HashSet<_> = webhook_types.iter().collect();
+        assert_eq!(unique.len(), webhook_types.len());
+
+        assert!(analytics_event_type::all().contains(&analytics_event_type::SHARE_TRIGGERED));
+    }
+
+    #[test]
+    fn test_lenient_hex() {
+        // Find a payload whose signature starts with a zero digit
+        let (payload, sig) = (0..)
+            .map(|i| format!(r#"{{"n":{}}}"#, i))
+            .map(|payload| {
+                let sig = sign(payload.as_bytes());
+                (payload, sig)
+            })
+            .find(|(_, sig)| sig.starts_with('0'))
+            .unwrap();
+        let stripped = sig.trim_start_matches('0');
+
+        let strict = VortexWebhooks::new(TEST_SECRET).unwrap();
+        assert!(strict.verify_signature(payload.as_bytes(), &sig.to_uppercase()));
+        assert!(!strict.verify_signature(payload.as_bytes(), stripped));
+
+        let lenient = VortexWebhooks::new(TEST_SECRET).unwrap().with_lenient_hex(true);
+        assert!(lenient.verify_signature(payload.as_bytes(), stripped));
+        assert!(lenient.verify_signature(payload.as_bytes(), &sig));
+        assert!(!lenient.verify_signature(payload.as_bytes(), "zz"));
+    }
+
+    #[test]
+    fn test_max_payload_bytes() {
+        let webhooks = VortexWebhooks::new(TEST_SECRET)
+            .unwrap()
+            .with_max_payload_bytes(SAMPLE_WEBHOOK.len());
+        let sig = sign(SAMPLE_WEBHOOK.as_bytes());
+        assert!(webhooks.construct_event(SAMPLE_WEBHOOK.as_bytes(), &sig).is_ok());
+
+        let padded = format!("{} ", SAMPLE_WEBHOOK);
+        let sig = sign(padded.as_bytes());
+        assert!(!webhooks.verify_signature(padded.as_bytes(), &sig));
+        let err = webhooks.construct_event(padded.as_bytes(), &sig).unwrap_err();
+        assert_eq!(err.signature_error_kind(), Some(SignatureErrorKind::PayloadTooLarge));
+    }
+
+    #[test]
+    fn test_max_json_depth() {
+        let webhooks = VortexWebhooks::new(TEST_SECRET).unwrap().with_max_json_depth(2);
+        let sig = sign(SAMPLE_WEBHOOK.as_bytes());
+        assert!(webhooks.construct_event(SAMPLE_WEBHOOK.as_bytes(), &sig).is_ok());
+
+        let nested = SAMPLE_WEBHOOK.replace(r#""user@test.com""#, r#"{"a":["[[{"]}"#);
+        let sig = sign(nested.as_bytes());
+        let err = webhooks.construct_event(nested.as_bytes(), &sig).unwrap_err();
+        assert!(matches!(err, VortexError::SerializationError(_)));
+        assert!(!json_depth_exceeds(br#"{"a":"[[[[{{"}"#, 1));
+    }
+
+    #[test]
+    fn test_try_parse_untrusted_bytes() {
+        let webhooks = VortexWebhooks::new(TEST_SECRET).unwrap().with_max_json_depth(8);
+        assert!(webhooks.try_parse(SAMPLE_WEBHOOK.as_bytes()).unwrap().is_webhook_event());
+
+        let deep = "[".repeat(64);
+        for input in [&b""[..], b"\xff\xfe", b"{", b"null", deep.as_bytes()] {
+            assert!(webhooks.try_parse(input).is_err());
+        }
+
+        let limited = webhooks.with_max_payload_bytes(8);
+        let err = limited.try_parse(SAMPLE_WEBHOOK.as_bytes()).unwrap_err();
+        assert!(matches!(err, VortexError::SerializationError(_)));
+        assert_eq!(err.signature_error_kind(), None);
     }
 
     #[test]
@@ -144,6 +900,73 @@ mod tests {
         assert!(event.is_analytics_event());
         let ae = event.as_analytics_event().unwrap();
         assert_eq!(ae.name, "widget_loaded");
+        assert!(ae.segmentation_parsed::<serde_json::Value>().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_analytics_segmentation_parsed() {
+        let payload = SAMPLE_ANALYTICS
+            .replace(r#""segmentation":null"#, r#""segmentation":"{\"plan\":\"pro\"}""#);
+        let webhooks = VortexWebhooks::new(TEST_SECRET).unwrap();
+        let event = webhooks.construct_event(payload.as_bytes(), &sign(payload.as_bytes())).unwrap();
+        let ae = event.as_analytics_event().unwrap();
+
+        let segmentation: HashMap<String, String> = ae.segmentation_parsed().unwrap().unwrap();
+        assert_eq!(segmentation["plan"], "pro");
+        assert!(ae.segmentation_parsed::<Vec<u32>>().is_err());
+    }
+
+    #[test]
+    fn test_on_verify_failure_receives_untrusted_fields() {
+        use std::sync::Mutex;
+
+        let seen = Arc::new(Mutex::new(None));
+        let seen_clone = seen.clone();
+        let webhooks = VortexWebhooks::new(TEST_SECRET)
+            .unwrap()
+            .with_on_verify_failure(move |failure| {
+                *seen_clone.lock().unwrap() = Some(failure.clone());
+            });
+
+        let result = webhooks.construct_event(SAMPLE_WEBHOOK.as_bytes(), "bad");
+        assert!(result.is_err());
+
+        let failure = seen.lock().unwrap().clone().unwrap();
+        assert_eq!(failure.payload_len, SAMPLE_WEBHOOK.len());
+        assert_eq!(failure.untrusted_id.as_deref(), Some("evt_1"));
+        assert_eq!(failure.untrusted_type.as_deref(), Some("invitation.accepted"));
+    }
+
+    #[test]
+    fn test_on_verify_failure_respects_max_json_depth() {
+        use std::sync::Mutex;
+
+        let seen = Arc::new(Mutex::new(None));
+        let seen_clone = seen.clone();
+        let webhooks = VortexWebhooks::new(TEST_SECRET)
+            .unwrap()
+            .with_max_json_depth(4)
+            .with_on_verify_failure(move |failure| {
+                *seen_clone.lock().unwrap() = Some(failure.clone());
+            });
+
+        let payload = format!(r#"{{"id":"evt_1","data":{}1{}}}"#, "[".repeat(16), "]".repeat(16));
+        assert!(webhooks.construct_event(payload.as_bytes(), "bad").is_err());
+
+        let failure = seen.lock().unwrap().clone().unwrap();
+        assert_eq!(failure.payload_len, payload.len());
+        assert_eq!(failure.untrusted_id, None);
+        assert_eq!(failure.untrusted_type, None);
+    }
+
+    #[test]
+    fn test_stateless_helpers() {
+        let sig = sign(SAMPLE_WEBHOOK.as_bytes());
+        assert!(verify_webhook_signature(TEST_SECRET, SAMPLE_WEBHOOK.as_bytes(), &sig));
+        assert!(!verify_webhook_signature("", SAMPLE_WEBHOOK.as_bytes(), &sig));
+
+        let event = construct_webhook_event(TEST_SECRET, SAMPLE_WEBHOOK.as_bytes(), &sig).unwrap();
+        assert!(event.is_webhook_event());
     }
 
     #[test]
@@ -42,6 +42,7 @@ async fn test_accept_invitations_with_user_fails_with_fake_key() {
 async fn test_accept_invitations_with_legacy_target_fails_with_fake_key() {
     let client = VortexClient::new("VRTX.dGVzdC1rZXk.test-secret".to_string());
     let target = InvitationTarget::email("legacy@example.com");
+    assert_eq!(target.target_type, InvitationTargetType::Email);
 
     let result = client.accept_invitations(vec!["test-inv".to_string()], target).await;
     assert!(result.is_err(), "Should fail with fake API key");
@@ -82,3 +83,49 @@ async fn test_validation_empty_targets_array() {
     let err_msg = format!("{:?}", result.err().unwrap());
     assert!(err_msg.contains("No targets provided"), "Error should mention no targets provided");
 }
+
+#[tokio::test]
+async fn test_accept_invitations_multi_validation_user_without_email_or_phone() {
+    let client = VortexClient::new("VRTX.dGVzdC1rZXk.test-secret".to_string());
+    let invalid_user = AcceptUser::new().with_name("Just a Name");
+
+    let result = client
+        .accept_invitations_multi(vec!["inv-1".to_string(), "inv-2".to_string()], invalid_user)
+        .await;
+    assert!(result.is_err(), "Should fail validation");
+
+    let err_msg = format!("{:?}", result.err().unwrap());
+    assert!(err_msg.contains("email or phone"), "Error should mention email or phone requirement");
+}
+
+#[tokio::test]
+async fn test_accept_invitations_rejects_reserved_attribute_keys() {
+    let client = VortexClient::new("VRTX.dGVzdC1rZXk.test-secret".to_string());
+    let user = AcceptUser::new().with_email("email@example.com");
+    let mut attributes = std::collections::HashMap::new();
+    attributes.insert("invitationIds".to_string(), serde_json::json!(["other"]));
+
+    let result = client
+        .accept_invitations_with_attributes(vec!["test-inv".to_string()], user, attributes)
+        .await;
+    assert!(result.is_err(), "Should reject reserved attribute key");
+
+    let err_msg = format!("{:?}", result.err().unwrap());
+    assert!(err_msg.contains("reserved"), "Error should mention the reserved key");
+}
+
+#[tokio::test]
+async fn test_accept_invitation_shares_batch_validation() {
+    let client = VortexClient::new("VRTX.dGVzdC1rZXk.test-secret".to_string());
+
+    let result = client
+        .accept_invitation("test-inv", AcceptUser::new().with_name("Just a Name"))
+        .await;
+    let err_msg = format!("{:?}", result.err().unwrap());
+    assert!(err_msg.contains("email or phone"), "Error should mention email or phone requirement");
+
+    let empty_targets: Vec<InvitationTarget> = vec![];
+    let result = client.accept_invitation("test-inv", empty_targets).await;
+    let err_msg = format!("{:?}", result.err().unwrap());
+    assert!(err_msg.contains("No targets provided"), "Error should mention no targets provided");
+}
@@ -0,0 +1,345 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use vortex_sdk::{
+    AcceptUser, CacheConfig, CircuitConfig, CreateInvitationRequest, CreateInvitationTarget,
+    HttpVersion, Inviter, Method, Metrics, RequestOptions, VortexApi, VortexClient,
+    VortexClientConfig, VortexError,
+};
+
+#[test]
+fn test_base_url_accessor() {
+    let client = VortexClient::with_base_url(
+        "VRTX.dGVzdC1rZXk.test-secret".to_string(),
+        "https://example.test".to_string(),
+    );
+    assert_eq!(client.base_url(), "https://example.test");
+}
+
+#[test]
+fn test_from_config() {
+    let config: VortexClientConfig = serde_json::from_str(
+        r#"{
+            "base_url": "https://example.test",
+            "auth_scheme": "bearer",
+            "cache_ttl": 10,
+            "http_version": "http2_prior_knowledge",
+            "circuit_breaker": { "failure_threshold": 3, "cooldown": 5 }
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(config.http_version, HttpVersion::Http2PriorKnowledge);
+    assert_eq!(config.cache_ttl, Some(Duration::from_secs(10)));
+    let circuit = config.circuit_breaker.as_ref().unwrap();
+    assert_eq!(circuit.cooldown, Duration::from_secs(5));
+    assert_eq!(circuit.window, CircuitConfig::default().window);
+
+    let json = serde_json::to_value(&config).unwrap();
+    assert_eq!(json["cache_ttl"], 10);
+    assert!(json.get("api_key").is_none());
+
+    let client = VortexClient::from_config("VRTX.dGVzdC1rZXk.test-secret".to_string(), config);
+    assert_eq!(client.base_url(), "https://example.test");
+}
+
+#[tokio::test]
+async fn test_http_error_includes_request_url() {
+    let client = VortexClient::with_base_url(
+        "VRTX.dGVzdC1rZXk.test-secret".to_string(),
+        "http://127.0.0.1:1".to_string(),
+    );
+
+    let result = client.get_invitation("inv-123").await;
+    match result {
+        Err(VortexError::HttpError(msg)) => {
+            assert!(msg.contains("GET http://127.0.0.1:1/api/v1/invitations/inv-123"));
+            assert!(!msg.contains("test-secret"));
+        }
+        other => panic!("Expected HttpError, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_circuit_breaker_opens_after_consecutive_failures() {
+    let client = VortexClient::with_base_url(
+        "VRTX.dGVzdC1rZXk.test-secret".to_string(),
+        "http://127.0.0.1:1".to_string(),
+    )
+    .with_circuit_breaker(
+        CircuitConfig::new()
+            .with_failure_threshold(2)
+            .with_cooldown(Duration::from_secs(60)),
+    );
+
+    for _ in 0..2 {
+        let result = client.get_invitation("inv-123").await;
+        assert!(matches!(result, Err(VortexError::HttpError(_))));
+    }
+
+    let result = client.get_invitation("inv-123").await;
+    assert!(matches!(result, Err(VortexError::CircuitOpen(_))));
+}
+
+#[tokio::test]
+async fn test_ping_unreachable_api() {
+    let client = VortexClient::with_base_url(
+        "VRTX.dGVzdC1rZXk.test-secret".to_string(),
+        "http://127.0.0.1:1".to_string(),
+    );
+    assert!(matches!(client.ping().await, Err(VortexError::HttpError(_))));
+}
+
+#[tokio::test]
+async fn test_request_escape_hatch_with_options() {
+    let client = VortexClient::with_base_url(
+        "VRTX.dGVzdC1rZXk.test-secret".to_string(),
+        "http://127.0.0.1:1".to_string(),
+    );
+    let options = RequestOptions::new()
+        .with_timeout(Duration::from_secs(1))
+        .with_idempotency_key("key-1");
+
+    let result: Result<serde_json::Value, _> = client
+        .request(Method::GET, "/api/v1/custom?x=1", None::<&()>, options)
+        .await;
+    match result {
+        Err(VortexError::HttpError(msg)) => assert!(msg.contains("/api/v1/custom?x=1")),
+        other => panic!("Expected HttpError, got {:?}", other),
+    }
+}
+
+#[derive(Default)]
+struct RecordingMetrics {
+    events: Mutex<Vec<String>>,
+}
+
+impl Metrics for RecordingMetrics {
+    fn on_request(&self, method: &Method, path: &str) {
+        self.events.lock().unwrap().push(format!("request {} {}", method, path));
+    }
+
+    fn on_response(&self, method: &Method, path: &str, status: Option<u16>, _elapsed: Duration) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("response {} {} {:?}", method, path, status));
+    }
+}
+
+#[tokio::test]
+async fn test_metrics_hooks_observe_failed_request() {
+    let metrics = Arc::new(RecordingMetrics::default());
+    let client = VortexClient::with_base_url(
+        "VRTX.dGVzdC1rZXk.test-secret".to_string(),
+        "http://127.0.0.1:1".to_string(),
+    )
+    .with_metrics(metrics.clone());
+
+    assert!(client.get_invitation("inv-123").await.is_err());
+    assert_eq!(
+        *metrics.events.lock().unwrap(),
+        vec![
+            "request GET /api/v1/invitations/inv-123",
+            "response GET /api/v1/invitations/inv-123 None",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_reinvite_pending_by_group_fails_when_listing_fails() {
+    let client = VortexClient::with_base_url(
+        "VRTX.dGVzdC1rZXk.test-secret".to_string(),
+        "http://127.0.0.1:1".to_string(),
+    );
+    let result = client.reinvite_pending_by_group("team", "team-123").await;
+    assert!(matches!(result, Err(VortexError::HttpError(_))));
+}
+
+#[tokio::test]
+async fn test_client_as_dyn_vortex_api() {
+    let api: Arc<dyn VortexApi> = Arc::new(VortexClient::with_base_url(
+        "VRTX.dGVzdC1rZXk.test-secret".to_string(),
+        "http://127.0.0.1:1".to_string(),
+    ));
+    let result = api.get_invitation("inv-123").await;
+    assert!(matches!(result, Err(VortexError::HttpError(_))));
+}
+
+#[tokio::test]
+async fn test_create_invitation_idempotent() {
+    let client = VortexClient::with_base_url(
+        "VRTX.dGVzdC1rZXk.test-secret".to_string(),
+        "http://127.0.0.1:1".to_string(),
+    );
+    let request = CreateInvitationRequest::new(
+        "widget-config-123",
+        CreateInvitationTarget::email("invitee@example.com"),
+        Inviter::new("user-456"),
+    );
+
+    let result = client.create_invitation_idempotent(&request, "").await;
+    assert!(matches!(result, Err(VortexError::InvalidRequest(_))));
+
+    let result = client.create_invitation_idempotent(&request, "job-1").await;
+    assert!(matches!(result, Err(VortexError::HttpError(msg)) if msg.contains("POST")));
+}
+
+/// Serve canned HTTP responses, one per connection, recording each request head
+async fn serve(responses: Vec<String>) -> (String, Arc<Mutex<Vec<String>>>) {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let seen = requests.clone();
+    tokio::spawn(async move {
+        for response in responses {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut head = Vec::new();
+            let mut buf = [0u8; 1024];
+            // Read up to the end of the headers; any body may arrive in the same read
+            while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = socket.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    break;
+                }
+                head.extend_from_slice(&buf[..n]);
+            }
+            seen.lock().unwrap().push(String::from_utf8_lossy(&head).to_lowercase());
+            socket.write_all(response.as_bytes()).await.unwrap();
+            socket.shutdown().await.unwrap();
+        }
+    });
+    (base_url, requests)
+}
+
+#[tokio::test]
+async fn test_invitation_cache_revalidates_with_etag() {
+    let body = r#"{"id":"inv-123","createdAt":"1970-01-01T00:00:00Z","invitationType":"single_use","status":"delivered","expired":false}"#;
+    let (base_url, requests) = serve(vec![
+        format!(
+            "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Type: application/json\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        ),
+        "HTTP/1.1 304 Not Modified\r\nETag: \"v1\"\r\nConnection: close\r\n\r\n".to_string(),
+    ])
+    .await;
+    let client = VortexClient::with_base_url("VRTX.dGVzdC1rZXk.test-secret".to_string(), base_url)
+        .with_invitation_cache(CacheConfig::new());
+
+    let first = client.get_invitation("inv-123").await.unwrap();
+    let second = client.get_invitation("inv-123").await.unwrap();
+    assert_eq!(first.id, "inv-123");
+    assert_eq!(second.id, "inv-123");
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(!requests[0].contains("if-none-match"));
+    assert!(requests[1].contains("if-none-match: \"v1\""));
+}
+
+#[tokio::test]
+async fn test_accept_invitation_with_result_bypasses_cache() {
+    let pending = r#"{"id":"inv-1","createdAt":"1970-01-01T00:00:00Z","invitationType":"single_use","status":"delivered","expired":false}"#;
+    let accepted = r#"{"id":"inv-1","createdAt":"1970-01-01T00:00:00Z","invitationType":"single_use","status":"accepted","expired":false,"accepts":[{"target":{"type":"email","value":"user@example.com"}}]}"#;
+    let ok = |body: &str| {
+        format!(
+            "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Type: application/json\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        )
+    };
+    let (base_url, requests) = serve(vec![ok(pending), ok(accepted), ok(accepted)]).await;
+    let client = VortexClient::with_base_url("VRTX.dGVzdC1rZXk.test-secret".to_string(), base_url)
+        .with_invitation_cache(CacheConfig::new());
+
+    // Cache a copy from before the user accepted elsewhere
+    assert!(client.get_invitation("inv-1").await.unwrap().is_pending());
+
+    let user = AcceptUser::new().with_email("user@example.com");
+    let result = client.accept_invitation_with_result("inv-1", user).await.unwrap();
+
+    assert!(!result.newly_accepted);
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 3);
+    assert!(requests[1].starts_with("get /api/v1/invitations/inv-1 "));
+    assert!(!requests[1].contains("if-none-match"));
+}
+
+#[tokio::test]
+async fn test_reinvite_guarded_bypasses_cache() {
+    let old = r#"{"id":"inv-1","createdAt":"1970-01-01T00:00:00Z","invitationType":"single_use","status":"delivered","expired":false}"#;
+    // A future timestamp counts as just sent
+    let recent = r#"{"id":"inv-1","createdAt":"1970-01-01T00:00:00Z","modifiedAt":"2999-01-01T00:00:00Z","invitationType":"single_use","status":"delivered","expired":false}"#;
+    let ok = |body: &str| {
+        format!(
+            "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Type: application/json\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        )
+    };
+    let (base_url, requests) = serve(vec![ok(old), ok(recent)]).await;
+    let client = VortexClient::with_base_url("VRTX.dGVzdC1rZXk.test-secret".to_string(), base_url)
+        .with_invitation_cache(CacheConfig::new());
+    client.get_invitation("inv-1").await.unwrap();
+
+    let result = client.reinvite_guarded("inv-1", Duration::from_secs(3600)).await;
+
+    assert!(matches!(result, Err(VortexError::InvalidRequest(_))));
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(!requests[1].contains("if-none-match"));
+}
+
+#[tokio::test]
+async fn test_request_sends_patch() {
+    let (base_url, requests) = serve(vec![
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 2\r\n\
+         Connection: close\r\n\r\n{}"
+            .to_string(),
+    ])
+    .await;
+    let client = VortexClient::with_base_url("VRTX.dGVzdC1rZXk.test-secret".to_string(), base_url);
+
+    let body = serde_json::json!({ "metadata": { "source": "test" } });
+    let result: serde_json::Value = client
+        .request(Method::PATCH, "/api/v1/invitations/inv-123", Some(&body), RequestOptions::new())
+        .await
+        .unwrap();
+
+    assert_eq!(result, serde_json::json!({}));
+    let requests = requests.lock().unwrap();
+    assert!(requests[0].starts_with("patch /api/v1/invitations/inv-123 "), "{}", requests[0]);
+}
+
+#[tokio::test]
+async fn test_revoke_invitations_by_target_reports_partial_failure() {
+    let body = r#"{"invitations":[{"id":"inv-1","createdAt":"1970-01-01T00:00:00Z","invitationType":"single_use","status":"delivered","expired":false},{"id":"inv-2","createdAt":"1970-01-01T00:00:00Z","invitationType":"single_use","status":"delivered","expired":false},{"id":"inv-3","createdAt":"1970-01-01T00:00:00Z","invitationType":"single_use","status":"accepted","expired":false},{"id":"inv-4","createdAt":"1970-01-01T00:00:00Z","invitationType":"single_use","status":"delivered","expired":true}]}"#;
+    let (base_url, requests) = serve(vec![
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        ),
+        "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+            .to_string(),
+        "HTTP/1.1 200 OK\r\nContent-Length: 4\r\nConnection: close\r\n\r\nnull".to_string(),
+    ])
+    .await;
+    let client = VortexClient::with_base_url("VRTX.dGVzdC1rZXk.test-secret".to_string(), base_url);
+
+    let result = client
+        .revoke_invitations_by_target("email", "user@example.com")
+        .await
+        .unwrap();
+
+    assert_eq!(result.failed.len(), 1);
+    assert_eq!(result.failed[0].0, "inv-1");
+    assert_eq!(result.succeeded.len(), 1);
+    assert_eq!(result.succeeded[0].id, "inv-2");
+    assert_eq!(requests.lock().unwrap().len(), 3);
+}
@@ -0,0 +1,187 @@
+use std::sync::{Arc, Mutex};
+use vortex_sdk::{
+    AcceptUser, CreateInvitationRequest, CreateInvitationTarget, DeleteOptions, Inviter, Method,
+    RequestOptions, VortexClient, VortexError,
+};
+
+#[tokio::test]
+async fn test_dry_run_skips_mutating_request() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = seen.clone();
+    let client = VortexClient::with_base_url(
+        "VRTX.dGVzdC1rZXk.test-secret".to_string(),
+        "http://127.0.0.1:1".to_string(),
+    )
+    .with_dry_run(true)
+    .with_dry_run_callback(move |req| {
+        seen_clone
+            .lock()
+            .unwrap()
+            .push(format!("{} {}", req.method, req.path));
+    });
+
+    let result = client.revoke_invitation("inv-123").await;
+    assert!(result.is_ok(), "Dry run should not hit the network");
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec!["DELETE /api/v1/invitations/inv-123".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn test_dry_run_returns_marker_when_response_cannot_be_synthesized() {
+    let client = VortexClient::with_base_url(
+        "VRTX.dGVzdC1rZXk.test-secret".to_string(),
+        "http://127.0.0.1:1".to_string(),
+    )
+    .with_dry_run(true)
+    .with_dry_run_callback(|_| {});
+
+    let user = AcceptUser::new().with_email("user@example.com");
+    let result = client.accept_invitation("inv-123", user).await;
+    assert!(matches!(result, Err(VortexError::DryRun(_))));
+}
+
+#[tokio::test]
+async fn test_dry_run_delete_by_group_reports_nothing_deleted() {
+    let client = VortexClient::with_base_url(
+        "VRTX.dGVzdC1rZXk.test-secret".to_string(),
+        "http://127.0.0.1:1".to_string(),
+    )
+    .with_dry_run(true)
+    .with_dry_run_callback(|_| {});
+
+    let result = client
+        .delete_invitations_by_group("workspace", "ws-1")
+        .await
+        .unwrap();
+    assert_eq!(result.deleted, None);
+    assert!(result.failed.is_empty());
+}
+
+#[tokio::test]
+async fn test_delete_by_group_skips_delete_when_count_check_fails() {
+    let seen = Arc::new(Mutex::new(0));
+    let seen_clone = seen.clone();
+    let client = VortexClient::with_base_url(
+        "VRTX.dGVzdC1rZXk.test-secret".to_string(),
+        "http://127.0.0.1:1".to_string(),
+    )
+    .with_dry_run(true)
+    .with_dry_run_callback(move |_| *seen_clone.lock().unwrap() += 1);
+
+    let options = DeleteOptions::new().with_confirm_count(3);
+    let result = client
+        .delete_invitations_by_group_with_options("workspace", "ws-1", &options)
+        .await;
+    assert!(matches!(result, Err(VortexError::HttpError(_))));
+    assert_eq!(*seen.lock().unwrap(), 0);
+
+    client
+        .delete_invitations_by_group_with_options("workspace", "ws-1", &DeleteOptions::new())
+        .await
+        .unwrap();
+    assert_eq!(*seen.lock().unwrap(), 1);
+}
+
+#[tokio::test]
+async fn test_dry_run_deactivate_invitation_patches() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = seen.clone();
+    let client = VortexClient::with_base_url(
+        "VRTX.dGVzdC1rZXk.test-secret".to_string(),
+        "http://127.0.0.1:1".to_string(),
+    )
+    .with_dry_run(true)
+    .with_dry_run_callback(move |req| {
+        let body = req.body.as_ref().map(|b| b.to_string()).unwrap_or_default();
+        seen_clone.lock().unwrap().push(format!("{} {} {}", req.method, req.path, body));
+    });
+
+    let result = client.deactivate_invitation("inv-123").await;
+    assert!(matches!(result, Err(VortexError::DryRun(_))));
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![r#"PATCH /api/v1/invitations/inv-123 {"deactivated":true}"#]
+    );
+}
+
+#[tokio::test]
+async fn test_email_target_normalized_on_create_and_lookup() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = seen.clone();
+    let client = VortexClient::with_base_url(
+        "VRTX.dGVzdC1rZXk.test-secret".to_string(),
+        "http://127.0.0.1:1".to_string(),
+    )
+    .with_dry_run(true)
+    .with_dry_run_callback(move |req| {
+        let body = req.body.clone().unwrap_or_default();
+        seen_clone.lock().unwrap().push(body["target"]["value"].clone());
+    });
+
+    let request = CreateInvitationRequest::new(
+        "widget-config-123",
+        CreateInvitationTarget::email(" User@Example.com "),
+        Inviter::new("user-456"),
+    );
+    let _ = client.create_invitation(&request).await;
+    assert_eq!(seen.lock().unwrap()[0], "user@example.com");
+
+    // Lookups are not skipped in dry-run mode; the unreachable URL shows the query sent
+    match client.get_invitations_by_target("email", "User@Example.com").await {
+        Err(VortexError::HttpError(msg)) => assert!(msg.contains("user%40example.com"), "{}", msg),
+        other => panic!("Expected HttpError, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_phone_target_normalized_on_create_and_lookup() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = seen.clone();
+    let client = VortexClient::with_base_url(
+        "VRTX.dGVzdC1rZXk.test-secret".to_string(),
+        "http://127.0.0.1:1".to_string(),
+    )
+    .with_dry_run(true)
+    .with_dry_run_callback(move |req| {
+        let body = req.body.clone().unwrap_or_default();
+        seen_clone.lock().unwrap().push(body["target"]["value"].clone());
+    });
+
+    assert!(CreateInvitationTarget::sms("555-0100").is_err());
+
+    let request = CreateInvitationRequest::new(
+        "widget-config-123",
+        CreateInvitationTarget::sms("+1 (555) 555-0100").unwrap(),
+        Inviter::new("user-456"),
+    );
+    let _ = client.create_invitation(&request).await;
+    assert_eq!(seen.lock().unwrap()[0], "+15555550100");
+
+    match client.get_invitations_by_target("sms", "+1 555-555-0100").await {
+        Err(VortexError::HttpError(msg)) => {
+            assert!(msg.contains("targetValue=%2B15555550100"), "{}", msg)
+        }
+        other => panic!("Expected HttpError, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_dry_run_still_sends_head_requests() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = seen.clone();
+    let client = VortexClient::with_base_url(
+        "VRTX.dGVzdC1rZXk.test-secret".to_string(),
+        "http://127.0.0.1:1".to_string(),
+    )
+    .with_dry_run(true)
+    .with_dry_run_callback(move |req| seen_clone.lock().unwrap().push(req.method.clone()));
+
+    let result: Result<serde_json::Value, _> = client
+        .request(Method::HEAD, "/api/v1/invitations", None::<&()>, RequestOptions::default())
+        .await;
+
+    assert!(matches!(result, Err(VortexError::HttpError(_))));
+    assert!(seen.lock().unwrap().is_empty());
+}
@@ -0,0 +1,215 @@
+use vortex_sdk::{
+    sort_invitations_by_created, sort_invitations_by_created_desc, Identifier, IdentifierType,
+    Invitation, InvitationStatus, InvitationSummary, InvitationTarget, InvitationTargetType,
+    InvitationsResponse, VortexError, VortexEvent,
+};
+
+#[test]
+fn test_identifier_typed_constructors() {
+    let email = Identifier::email("user@example.com");
+    assert_eq!(email.identifier_type, "email");
+    assert_eq!(email.kind(), IdentifierType::Email);
+
+    let sms = Identifier::sms("+15555550100").unwrap();
+    assert_eq!(sms.identifier_type, "sms");
+    assert_eq!(sms.kind(), IdentifierType::Sms);
+}
+
+#[test]
+fn test_identifier_validate() {
+    assert!(Identifier::email("user@example.com").validate().is_ok());
+    assert!(Identifier::email("not-an-email").validate().is_err());
+    assert!(Identifier::sms("+15555550100").unwrap().validate().is_ok());
+    assert!(Identifier::new("sms", "555-CALL-NOW").validate().is_err());
+
+    let custom = Identifier::new("emial", "user@example.com");
+    assert_eq!(custom.kind(), IdentifierType::Custom("emial".to_string()));
+    assert!(custom.validate().is_ok());
+}
+
+#[test]
+fn test_sms_targets_are_normalized() {
+    let target = InvitationTarget::sms("+1 (555) 555-0100").unwrap();
+    assert_eq!(target.target_type, InvitationTargetType::Phone);
+    assert_eq!(target.value, "+15555550100");
+
+    let identifier = Identifier::sms("+44 20 7946 0958").unwrap();
+    assert_eq!(identifier.value, "+442079460958");
+}
+
+#[test]
+fn test_sms_targets_reject_invalid_numbers() {
+    assert!(InvitationTarget::sms("555-0100").is_err());
+    assert!(InvitationTarget::sms("+1-555-CALL-NOW").is_err());
+    assert!(Identifier::sms("+0123456789").is_err());
+    assert!(Identifier::sms("+1234567890123456").is_err());
+}
+
+#[test]
+fn test_invitation_target_matches() {
+    let email = InvitationTarget::email("User@Example.com");
+    assert!(email.matches("email", "user@example.com"));
+    assert!(!email.matches("phone", "user@example.com"));
+
+    let phone = InvitationTarget::phone("+15555550100");
+    assert!(phone.matches("sms", "+1 (555) 555-0100"));
+    assert!(!phone.matches("phone", "+15555550101"));
+}
+
+#[test]
+fn test_invitation_target_normalization() {
+    assert_eq!(InvitationTarget::email(" User@Example.com").value, "user@example.com");
+
+    let phone = InvitationTarget::phone("+1 (555) 555-0100").normalized();
+    assert_eq!(phone.value, "+15555550100");
+
+    let internal = InvitationTarget::new(InvitationTargetType::Internal, "User-ABC");
+    assert_eq!(internal.normalized().value, "User-ABC");
+}
+
+const SAMPLE_INVITATION: &str = r#"{
+    "id": "inv_1",
+    "invitationType": "single_use",
+    "status": "delivered",
+    "expired": false,
+    "target": [
+        { "type": "email", "value": "user@example.com" },
+        { "type": "phone", "value": "+15555550100" }
+    ]
+}"#;
+
+#[test]
+fn test_invitation_display() {
+    let invitation: Invitation = serde_json::from_str(SAMPLE_INVITATION).unwrap();
+    assert_eq!(
+        invitation.to_string(),
+        "Invitation inv_1 [delivered] -> email:user@example.com, phone:+15555550100"
+    );
+}
+
+#[test]
+fn test_invitation_summary() {
+    let invitation: Invitation = serde_json::from_str(SAMPLE_INVITATION).unwrap();
+    let summary = InvitationSummary::from(&invitation);
+    assert_eq!(summary.id, "inv_1");
+    assert_eq!(summary.status, InvitationStatus::Delivered);
+    assert_eq!(summary.accept_count, 0);
+    assert_eq!(summary.targets[1].target_type, InvitationTargetType::Phone);
+
+    let json = serde_json::to_value(&summary).unwrap();
+    assert_eq!(json["targets"][0]["value"], "user@example.com");
+    assert_eq!(json["acceptCount"], 0);
+}
+
+#[test]
+fn test_invitation_target_values() {
+    let invitation: Invitation = serde_json::from_str(SAMPLE_INVITATION).unwrap();
+    assert_eq!(invitation.target_emails(), vec!["user@example.com"]);
+    assert_eq!(invitation.target_phones(), vec!["+15555550100"]);
+
+    let sms = SAMPLE_INVITATION.replace(r#""type": "phone""#, r#""type": "sms""#);
+    let invitation: Invitation = serde_json::from_str(&sms).unwrap();
+    assert_eq!(invitation.target_phones(), vec!["+15555550100"]);
+}
+
+#[test]
+fn test_invitation_is_pending() {
+    let mut invitation: Invitation = serde_json::from_str(SAMPLE_INVITATION).unwrap();
+    assert!(invitation.is_pending());
+
+    invitation.status = InvitationStatus::AcceptedElsewhere;
+    assert!(!invitation.is_pending());
+
+    invitation.status = InvitationStatus::Sent;
+    invitation.deactivated = true;
+    assert!(!invitation.is_pending());
+}
+
+#[test]
+fn test_invitation_is_expired() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let mut invitation: Invitation = serde_json::from_str(SAMPLE_INVITATION).unwrap();
+    invitation.expired = false;
+    invitation.expires = None;
+    assert!(!invitation.is_expired());
+
+    invitation.expires = Some("2026-03-01T00:00:00Z".to_string());
+    let expires = UNIX_EPOCH + Duration::from_secs(1772323200);
+    assert_eq!(invitation.expires_time(), Some(expires));
+    assert!(!invitation.is_expired_at(expires - Duration::from_secs(1)));
+    assert!(invitation.is_expired_at(expires));
+
+    invitation.expires = None;
+    invitation.expired = true;
+    assert!(invitation.is_expired_at(UNIX_EPOCH));
+}
+
+#[test]
+fn test_sort_invitations_by_created() {
+    let invitation = |id: &str, created_at: &str| -> Invitation {
+        let mut invitation: Invitation = serde_json::from_str(SAMPLE_INVITATION).unwrap();
+        invitation.id = id.to_string();
+        invitation.created_at = created_at.to_string();
+        invitation
+    };
+    // Lexicographically "b" sorts before "a", but it is an hour later in UTC
+    let mut invitations = vec![
+        invitation("b", "2026-02-25T12:30:00.5+02:00"),
+        invitation("a", "2026-02-25T09:30:00Z"),
+        invitation("bad", "not a timestamp"),
+    ];
+
+    sort_invitations_by_created(&mut invitations);
+    let ids: Vec<&str> = invitations.iter().map(|i| i.id.as_str()).collect();
+    assert_eq!(ids, vec!["bad", "a", "b"]);
+
+    sort_invitations_by_created_desc(&mut invitations);
+    let ids: Vec<&str> = invitations.iter().map(|i| i.id.as_str()).collect();
+    assert_eq!(ids, vec!["b", "a", "bad"]);
+
+    let a = invitation("a", "1970-01-01T00:00:01Z");
+    assert_eq!(
+        a.created_at_time(),
+        Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(1))
+    );
+    assert!(a.cmp_by_created_at(&invitations[0]).is_lt());
+}
+
+#[test]
+fn test_from_json_constructors() {
+    let invitation = Invitation::from_json(SAMPLE_INVITATION).unwrap();
+    assert!(matches!(Invitation::from_json("{"), Err(VortexError::SerializationError(_))));
+
+    let body = format!(r#"{{"invitations":[{}]}}"#, SAMPLE_INVITATION);
+    let response = InvitationsResponse::from_json(&body).unwrap();
+    assert_eq!(response.invitations.unwrap()[0].id, invitation.id);
+
+    let event = VortexEvent::from_json(
+        r#"{"id":"evt_1","type":"invitation.accepted","timestamp":"2026-02-25T12:00:00Z","accountId":"acc_1","environmentId":null,"sourceTable":"invitations","operation":"update","data":{}}"#,
+    )
+    .unwrap();
+    assert!(event.is_webhook_event());
+    assert!(VortexEvent::from_json("[]").is_err());
+}
+
+#[test]
+fn test_invitations_response_into_iter() {
+    let body = format!(r#"{{"invitations":[{0},{0}]}}"#, SAMPLE_INVITATION);
+    let response = InvitationsResponse::from_json(&body).unwrap();
+    assert_eq!(response.clone().into_iter().count(), 2);
+    assert_eq!(response.into_vec().len(), 2);
+
+    let missing = InvitationsResponse::from_json("{}").unwrap();
+    assert!(missing.into_iter().next().is_none());
+}
+
+#[test]
+fn test_error_display() {
+    let err = VortexError::ApiError("API request failed: 500".to_string());
+    assert_eq!(err.to_string(), "API error: API request failed: 500");
+
+    let err = vortex_sdk::VortexWebhooks::new("").err().unwrap();
+    assert_eq!(err.to_string(), "Webhook signature error: Webhook secret must not be empty.");
+    assert!(std::error::Error::source(&err).is_none());
+}